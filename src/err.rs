@@ -1,9 +1,13 @@
 use crate::mat::dims::Dimensions;
 use std::fmt::{Display, Formatter, Result};
+#[cfg(feature = "io")]
+use std::io;
 
 #[derive(Debug, PartialEq)]
 pub enum MatrixError {
     IndexOutOfBounds(usize),
+    NotSquare(Dimensions),
+    Singular,
 }
 
 #[derive(Debug, PartialEq)]
@@ -12,6 +16,26 @@ pub enum DimensionError {
     InvalidInputDimensions(usize, usize),
     NoMatch(Dimensions, Dimensions, String),
     NoSquare,
+    NoSymmetric,
+    OutOfBounds(usize, usize),
+    Ragged(usize, usize, usize),
+    InconsistentCorner,
+    NoConvergence(usize),
+    ComplexEigenvalues,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseMatrixError {
+    InvalidEntry(usize, usize, String),
+    InvalidLine(usize, String),
+    Dimension(DimensionError),
+}
+
+#[cfg(feature = "io")]
+#[derive(Debug)]
+pub enum MatrixIoError {
+    Io(io::Error),
+    Parse(ParseMatrixError),
 }
 
 impl Display for MatrixError {
@@ -21,6 +45,10 @@ impl Display for MatrixError {
                 f,
                 "Tried to access a matrix at index `{idx}`, which is out of bounds.",
             )?,
+            MatrixError::NotSquare(dims) => {
+                write!(f, "Not a square matrix. Cannot invert {dims} matrix.",)?
+            }
+            MatrixError::Singular => write!(f, "Singular matrix. The matrix is not invertible.")?,
         }
         Ok(())
     }
@@ -40,7 +68,74 @@ impl Display for DimensionError {
             DimensionError::NoSquare => {
                 write!(f, "Not a square matrix. Rows and cols need to be the same.")?
             }
+            DimensionError::NoSymmetric => write!(f, "Not a symmetric matrix.")?,
+            DimensionError::OutOfBounds(idx, len) => write!(
+                f,
+                "Index `{idx}` is out of bounds for a dimension of size `{len}`.",
+            )?,
+            DimensionError::Ragged(idx, len, correct_len) => write!(
+                f,
+                "Ragged input. Line `{idx}` has length {len}, but should have length {correct_len}.",
+            )?,
+            DimensionError::InconsistentCorner => write!(
+                f,
+                "Inconsistent input. The first column and the first row need to start with the same entry.",
+            )?,
+            DimensionError::NoConvergence(max_iter) => write!(
+                f,
+                "No convergence. The iteration did not converge within {max_iter} sweeps.",
+            )?,
+            DimensionError::ComplexEigenvalues => {
+                write!(f, "Complex eigenvalues. Only real eigenvalues are supported.")?
+            }
         }
         Ok(())
     }
 }
+
+impl Display for ParseMatrixError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            ParseMatrixError::InvalidEntry(row, col, token) => write!(
+                f,
+                "Cannot parse entry `{token}` in row {row} and column {col}.",
+            )?,
+            ParseMatrixError::InvalidLine(line, content) => {
+                write!(f, "Cannot parse line {line}: `{content}`.")?
+            }
+            ParseMatrixError::Dimension(err) => write!(f, "{err}")?,
+        }
+        Ok(())
+    }
+}
+
+impl From<DimensionError> for ParseMatrixError {
+    fn from(err: DimensionError) -> ParseMatrixError {
+        ParseMatrixError::Dimension(err)
+    }
+}
+
+#[cfg(feature = "io")]
+impl Display for MatrixIoError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            MatrixIoError::Io(err) => write!(f, "{err}")?,
+            MatrixIoError::Parse(err) => write!(f, "{err}")?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "io")]
+impl From<io::Error> for MatrixIoError {
+    fn from(err: io::Error) -> MatrixIoError {
+        MatrixIoError::Io(err)
+    }
+}
+
+#[cfg(feature = "io")]
+impl From<ParseMatrixError> for MatrixIoError {
+    fn from(err: ParseMatrixError) -> MatrixIoError {
+        MatrixIoError::Parse(err)
+    }
+}
@@ -1,8 +1,11 @@
 mod _mat;
 pub mod dims;
 mod smat;
+pub mod sparse;
 mod vec;
+mod view;
 use dims::Dimensions;
+use num_traits::Zero;
 
 /// Represents a matrix.
 #[derive(Debug, PartialEq, Clone)]
@@ -18,6 +21,115 @@ pub struct Vector<T> {
     entries: Vec<T>,
 }
 
+/// Borrowed view into a rectangular block of a [`Matrix`], created with [`Matrix::view`].
+///
+/// The view does not copy any entries. Indexing a view works like indexing a matrix, `view[i]`
+/// is the `i`-th row of the block and `view[i][j]` the entry in row `i` and column `j`.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// let mat = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9};
+/// let view = mat.view(1.., 1..)?;
+/// assert_eq!(view[0], [5, 6]);
+/// assert_eq!(view[1][0], 8);
+/// assert_eq!(view.to_matrix(), matrix!{5, 6; 8, 9});
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    dims: Dimensions,
+    stride: usize,
+    data: &'a [T],
+}
+
+/// Mutably borrowed view into a rectangular block of a [`Matrix`], created with
+/// [`Matrix::view_mut`].
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// let mut mat = Matrix::zero(3, 3)?;
+/// let mut view = mat.view_mut(..2, 1..)?;
+/// view[1][1] = 4;
+/// assert_eq!(mat, matrix!{0, 0, 0; 0, 0, 4; 0, 0, 0});
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    dims: Dimensions,
+    stride: usize,
+    data: &'a mut [T],
+}
+
+/// Options for reading and writing matrices as CSV with [`Matrix::from_csv_with`] and
+/// [`Matrix::to_csv_with`].
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::{CsvOptions, Matrix};
+/// # use libmat::matrix;
+/// # use libmat::err::MatrixIoError;
+/// # fn main() -> Result<(), MatrixIoError> {
+/// let options = CsvOptions::new().delimiter(';');
+/// let mat: Matrix<i32> = Matrix::from_csv_with("1;2\n3;4".as_bytes(), &options)?;
+/// assert_eq!(mat, matrix!{1, 2; 3, 4});
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "io")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CsvOptions {
+    delimiter: char,
+}
+
+/// The LaTeX matrix environment used by [`Matrix::to_latex`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LatexEnv {
+    /// `pmatrix`, with parentheses
+    PMatrix,
+    /// `bmatrix`, with square brackets
+    BMatrix,
+    /// `vmatrix`, with vertical bars as for determinants
+    VMatrix,
+}
+
+/// The magnitude of an entry, used to choose the pivots in [`Matrix::lupdecompose`] and
+/// everything built on it, like [`Matrix::det`], [`Matrix::solve`] and [`Matrix::try_inverse`].
+///
+/// Only the order of the magnitudes matters, so a type does not need to return its absolute
+/// value. A complex number type can use its squared norm, for example. Implementations are
+/// provided for the signed integer and floating point types.
+pub trait PivotMagnitude {
+    type Magnitude: PartialOrd + Zero;
+
+    fn pivot_magnitude(&self) -> Self::Magnitude;
+}
+
+/// Complex conjugation of an entry, used by [`Matrix::conjugate_transpose`],
+/// [`Matrix::is_hermitian`] and [`Matrix::is_unitary`].
+///
+/// For real types the conjugate is the entry itself. Implementations are provided for the signed
+/// integer and floating point types, where signed integers measure their modulus as the unsigned
+/// type of the same width. Unsigned types are left out, since the differences that
+/// [`Matrix::is_hermitian`] and [`Matrix::is_unitary`] take would overflow.
+pub trait Conjugate {
+    /// The type of the absolute value, which tolerances are given in.
+    type Real: PartialOrd;
+
+    fn conjugate(&self) -> Self;
+
+    fn modulus(&self) -> Self::Real;
+}
+
 /// Statically sized matrix.
 ///
 /// SMatrix is used almost just like Matrix, but its size is known at compile-time,
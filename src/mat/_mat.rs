@@ -1,3 +1,15 @@
+mod mat_decomp;
+mod mat_eig;
+mod mat_func;
 mod mat_impl;
+mod mat_io;
+mod mat_norm;
 mod mat_ops;
+mod mat_props;
+mod mat_simd;
+mod mat_solve;
 mod mat_traits;
+
+pub(crate) use mat_impl::resolve_range;
+pub(crate) use mat_norm::{frobenius_norm, inf_norm, max_norm, one_norm};
+pub(crate) use mat_traits::fmt_rows;
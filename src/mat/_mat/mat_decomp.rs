@@ -0,0 +1,540 @@
+use crate::err::DimensionError;
+use crate::mat::{Matrix, Vector};
+use num_traits::Float;
+use std::cmp::Ordering;
+
+/// Maximum number of sweeps used by [Matrix::svd].
+const SVD_MAX_SWEEPS: usize = 100;
+
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// Calculate the QR decomposition of a matrix using Householder reflections.
+    ///
+    /// Returns the orthogonal matrix `Q` with dimensions `rows x rows` and the upper triangular
+    /// matrix `R` with the same dimensions as the original matrix, so that `A = QR`.
+    /// If the matrix does not have full rank, `None` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{12.0, -51.0, 4.0; 6.0, 167.0, -68.0; -4.0, 24.0, -41.0};
+    /// let (q, r) = mat_a.qr_decompose()?.unwrap();
+    /// assert!((r[0][0].abs() - 14.0).abs() < 1e-10);
+    /// assert_eq!((r[1][0], r[2][0], r[2][1]), (0.0, 0.0, 0.0));
+    /// let qr = (q * r)?;
+    /// for i in 0..3 {
+    ///     for j in 0..3 {
+    ///         assert!((qr[i][j] - mat_a[i][j]).abs() < 1e-10);
+    ///     }
+    /// }
+    /// let mat_b: Matrix<f64> = matrix!{1.0, 2.0; 2.0, 4.0}; // not full rank
+    /// assert_eq!(mat_b.qr_decompose()?, None);
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn qr_decompose(&self) -> Result<Option<(Matrix<T>, Matrix<T>)>, DimensionError> {
+        let (q, r) = self.householder_qr();
+        let rank = self.rows().min(self.cols());
+        let max_diag = (0..rank).fold(T::zero(), |max, i| max.max(r[i][i].abs()));
+        let tol = T::epsilon() * T::from(self.rows().max(self.cols())).unwrap() * max_diag;
+        if max_diag.is_zero() || (0..rank).any(|i| r[i][i].abs() <= tol) {
+            Ok(None)
+        } else {
+            Ok(Some((q, r)))
+        }
+    }
+
+    /// Calculate the thin QR decomposition of a matrix using Householder reflections.
+    ///
+    /// For a matrix with dimensions `m x n` and `k = min(m, n)`, returns the matrix `Q` with
+    /// dimensions `m x k` and orthonormal columns and the upper triangular matrix `R` with
+    /// dimensions `k x n`, so that `A = QR`. Unlike [qr_decompose](Matrix::qr_decompose),
+    /// this works for rank deficient matrices as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{3.0, 1.0; 4.0, 2.0; 0.0, 2.0};
+    /// let (q, r) = mat_a.qr()?;
+    /// assert_eq!((q.rows(), q.cols()), (3, 2));
+    /// assert_eq!((r.rows(), r.cols()), (2, 2));
+    /// assert!((r[0][0].abs() - 5.0).abs() < 1e-10);
+    /// # Ok(()) }
+    /// ```
+    pub fn qr(&self) -> Result<(Matrix<T>, Matrix<T>), DimensionError> {
+        let (q, r) = self.householder_qr();
+        let k = self.rows().min(self.cols());
+        let q_thin = (0..q.rows())
+            .flat_map(|i| q[i][..k].to_vec())
+            .collect::<Vec<T>>();
+        let r_thin = r.matrix[..k * r.cols()].to_vec();
+        Ok((
+            Matrix::from_vec(q.rows(), k, q_thin)?,
+            Matrix::from_vec(k, r.cols(), r_thin)?,
+        ))
+    }
+
+    /// Calculate the Cholesky decomposition of a symmetric positive-definite matrix.
+    ///
+    /// Returns the lower triangular matrix `L` so that `A = LLᵀ`. Only the lower triangle of
+    /// the matrix is read, so symmetry is not checked. If the matrix is not positive-definite,
+    /// `None` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{4.0, 12.0, -16.0; 12.0, 37.0, -43.0; -16.0, -43.0, 98.0};
+    /// let mat_l = matrix!{2.0, 0.0, 0.0; 6.0, 1.0, 0.0; -8.0, 5.0, 3.0};
+    /// assert!(mat_a.cholesky()?.unwrap().approx_eq(&mat_l, 1e-12));
+    /// let mat_b: Matrix<f64> = matrix!{1.0, 2.0; 2.0, 1.0}; // not positive-definite
+    /// assert_eq!(mat_b.cholesky()?, None);
+    /// # Ok(()) }
+    /// ```
+    pub fn cholesky(&self) -> Result<Option<Matrix<T>>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        let mut l: Matrix<T> = Matrix::zero(dim, dim)?;
+        for j in 0..dim {
+            let pivot = (0..j).fold(self[j][j], |sum, k| sum - l[j][k] * l[j][k]);
+            if pivot <= T::zero() {
+                return Ok(None);
+            }
+            l[j][j] = pivot.sqrt();
+            for i in (j + 1)..dim {
+                let sum = (0..j).fold(self[i][j], |sum, k| sum - l[i][k] * l[j][k]);
+                l[i][j] = sum / l[j][j];
+            }
+        }
+        Ok(Some(l))
+    }
+
+    /// Solve the linear system `Ax = b` for a symmetric positive-definite matrix using its
+    /// Cholesky decomposition.
+    ///
+    /// Returns `None` if the matrix is not positive-definite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{4.0, 2.0; 2.0, 2.0};
+    /// let x = Matrix::from(mat_a.cholesky_solve(&vector![6.0, 4.0])?.unwrap());
+    /// assert!(x.approx_eq(&Matrix::from(vector![1.0, 1.0]), 1e-12));
+    /// # Ok(()) }
+    /// ```
+    pub fn cholesky_solve(&self, b: &Vector<T>) -> Result<Option<Vector<T>>, DimensionError> {
+        if b.len() != self.rows() {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                b.dims,
+                "solve".to_owned(),
+            ));
+        }
+        let l = match self.cholesky()? {
+            Some(l) => l,
+            None => return Ok(None),
+        };
+        let dim = self.rows();
+        // L y = b
+        let mut x = b.to_vec();
+        for i in 0..dim {
+            x[i] = (0..i).fold(x[i], |sum, k| sum - l[i][k] * x[k]) / l[i][i];
+        }
+        // Lᵀ x = y
+        for i in (0..dim).rev() {
+            x[i] = ((i + 1)..dim).fold(x[i], |sum, k| sum - l[k][i] * x[k]) / l[i][i];
+        }
+        Ok(Some(x.into()))
+    }
+
+    /// Calculate the LDLᵀ decomposition of a symmetric matrix, which does not need to be
+    /// positive-definite.
+    ///
+    /// Returns the unit lower triangular matrix `L` and the diagonal of `D` so that `A = LDLᵀ`.
+    /// Like [`Matrix::cholesky`], only the lower triangle of the matrix is read. No pivoting is
+    /// done, so `None` is returned whenever a zero pivot occurs, which includes every singular
+    /// matrix but also some nonsingular ones, e.g. `[[0, 1], [1, 0]]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{4.0, 2.0; 2.0, -1.0}; // indefinite
+    /// let (l, d) = mat_a.ldl()?.unwrap();
+    /// assert_eq!(l, matrix!{1.0, 0.0; 0.5, 1.0});
+    /// assert_eq!(d, vec![4.0, -2.0]);
+    /// assert_eq!(matrix!{1.0, 2.0; 2.0, 4.0}.ldl()?, None);
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn ldl(&self) -> Result<Option<(Matrix<T>, Vec<T>)>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        let mut l: Matrix<T> = Matrix::one(dim)?;
+        let mut d = vec![T::zero(); dim];
+        for j in 0..dim {
+            d[j] = (0..j).fold(self[j][j], |sum, k| sum - l[j][k] * l[j][k] * d[k]);
+            if d[j] == T::zero() {
+                return Ok(None);
+            }
+            for i in (j + 1)..dim {
+                let sum = (0..j).fold(self[i][j], |sum, k| sum - l[i][k] * l[j][k] * d[k]);
+                l[i][j] = sum / d[j];
+            }
+        }
+        Ok(Some((l, d)))
+    }
+
+    /// Reduce a square matrix to upper Hessenberg form with Householder reflections.
+    ///
+    /// Returns the matrix `H`, which is zero below its first subdiagonal, and the orthogonal
+    /// matrix `Q` so that `A = QHQᵀ`. For symmetric matrices `H` is tridiagonal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{4.0, 1.0, 2.0; 3.0, 5.0, 1.0; 4.0, 2.0, 6.0};
+    /// let (h, q) = mat_a.hessenberg()?;
+    /// assert!(h[2][0].abs() < 1e-12);
+    /// let qhqt = ((q.clone() * h)? * q.transpose())?;
+    /// assert!(qhqt.approx_eq(&mat_a, 1e-12));
+    /// # Ok(()) }
+    /// ```
+    pub fn hessenberg(&self) -> Result<(Matrix<T>, Matrix<T>), DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        let two = T::one() + T::one();
+        let mut h = self.clone();
+        let mut q: Matrix<T> = Matrix::one(dim)?;
+
+        for k in 0..dim.saturating_sub(2) {
+            let norm_x = ((k + 1)..dim)
+                .fold(T::zero(), |sum, i| sum + h[i][k] * h[i][k])
+                .sqrt();
+            if norm_x.is_zero() {
+                continue;
+            }
+            let alpha = if h[k + 1][k] > T::zero() {
+                -norm_x
+            } else {
+                norm_x
+            };
+
+            let mut v: Vec<T> = ((k + 1)..dim).map(|i| h[i][k]).collect();
+            v[0] = v[0] - alpha;
+            let norm_v = v.iter().fold(T::zero(), |sum, x| sum + *x * *x).sqrt();
+            if norm_v.is_zero() {
+                continue;
+            }
+            v.iter_mut().for_each(|x| *x = *x / norm_v);
+
+            // H = P H
+            for j in k..dim {
+                let dot = ((k + 1)..dim).fold(T::zero(), |sum, i| sum + v[i - k - 1] * h[i][j]);
+                for i in (k + 1)..dim {
+                    h[i][j] = h[i][j] - two * v[i - k - 1] * dot;
+                }
+            }
+            // H = H P and Q = Q P
+            for m in [&mut h, &mut q].iter_mut() {
+                for i in 0..dim {
+                    let dot = ((k + 1)..dim).fold(T::zero(), |sum, j| sum + m[i][j] * v[j - k - 1]);
+                    for j in (k + 1)..dim {
+                        m[i][j] = m[i][j] - two * dot * v[j - k - 1];
+                    }
+                }
+            }
+
+            h[k + 1][k] = alpha;
+            for i in (k + 2)..dim {
+                h[i][k] = T::zero();
+            }
+        }
+        Ok((h, q))
+    }
+
+    /// Calculate the singular value decomposition of a matrix.
+    ///
+    /// Returns the matrices `U` and `Vᵀ` with orthonormal columns and rows respectively, and the
+    /// singular values sorted in descending order, so that `A = U diag(s) Vᵀ`. For a matrix with
+    /// dimensions `m x n` and `k = min(m, n)`, `U` has dimensions `m x k` and `Vᵀ` has dimensions `k x n`.
+    ///
+    /// The decomposition is calculated with the one-sided Jacobi method. If it does not converge
+    /// within 100 sweeps, which can happen for matrices with non-finite entries,
+    /// [`DimensionError::NoConvergence`] is returned. See
+    /// [svd_with_max_iter](Matrix::svd_with_max_iter) to control the number of iterations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{3.0, 2.0, 2.0; 2.0, 3.0, -2.0};
+    /// let (u, s, vt) = mat_a.svd()?;
+    /// assert!((s[0] - 5.0).abs() < 1e-10);
+    /// assert!((s[1] - 3.0).abs() < 1e-10);
+    /// let usv = ((u * Matrix::diag_with(2, &s)?)? * vt)?;
+    /// for i in 0..2 {
+    ///     for j in 0..3 {
+    ///         assert!((usv[i][j] - mat_a[i][j]).abs() < 1e-10);
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn svd(&self) -> Result<(Matrix<T>, Vec<T>, Matrix<T>), DimensionError> {
+        self.svd_with_max_iter(SVD_MAX_SWEEPS)?
+            .ok_or(DimensionError::NoConvergence(SVD_MAX_SWEEPS))
+    }
+
+    /// Calculate the singular value decomposition of a matrix with at most `max_iter` sweeps.
+    ///
+    /// Behaves like [svd](Matrix::svd), but returns `None` if the decomposition did not converge
+    /// within `max_iter` sweeps of the one-sided Jacobi method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{1.0, 2.0; 3.0, 4.0; 5.0, 6.0};
+    /// assert!(mat_a.svd_with_max_iter(50)?.is_some());
+    /// assert!(mat_a.svd_with_max_iter(0)?.is_none());
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn svd_with_max_iter(
+        &self,
+        max_iter: usize,
+    ) -> Result<Option<(Matrix<T>, Vec<T>, Matrix<T>)>, DimensionError> {
+        let (u, s, vt, converged) = self.jacobi_svd(max_iter);
+        if converged {
+            Ok(Some((u, s, vt)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Calculate the rank of a matrix by counting the singular values greater than `eps`.
+    ///
+    /// This is numerically more robust than [rank](Matrix::rank), which uses Gaussian elimination
+    /// and works for integer types as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{1.0, 2.0, 3.0; 2.0, 4.0, 6.0; 1.0, 0.0, 1.0};
+    /// assert_eq!(mat_a.rank_with_tol(1e-10)?, 2);
+    /// # Ok(()) }
+    /// ```
+    pub fn rank_with_tol(&self, eps: T) -> Result<usize, DimensionError> {
+        let (_, s, _) = self.svd()?;
+        Ok(s.iter().filter(|x| **x > eps).count())
+    }
+
+    fn jacobi_svd(&self, max_sweeps: usize) -> (Matrix<T>, Vec<T>, Matrix<T>, bool) {
+        if self.rows() < self.cols() {
+            let (u, s, vt, converged) = self.transpose().jacobi_svd(max_sweeps);
+            return (vt.transpose(), s, u.transpose(), converged);
+        }
+        let rows = self.rows();
+        let cols = self.cols();
+        let two = T::one() + T::one();
+        let mut u = self.clone();
+        let mut v: Matrix<T> = Matrix::one(cols).unwrap();
+
+        // columns below this squared norm are rounding noise, rotating them never converges
+        let negligible = T::epsilon()
+            * T::epsilon()
+            * self.matrix.iter().fold(T::zero(), |sum, x| sum + *x * *x);
+        let mut converged = false;
+        for _ in 0..max_sweeps {
+            let mut rotated = false;
+            for p in 0..cols {
+                for q in (p + 1)..cols {
+                    let (alpha, beta, gamma) =
+                        (0..rows).fold((T::zero(), T::zero(), T::zero()), |(a, b, c), i| {
+                            (
+                                a + u[i][p] * u[i][p],
+                                b + u[i][q] * u[i][q],
+                                c + u[i][p] * u[i][q],
+                            )
+                        });
+                    if gamma.is_zero()
+                        || gamma.abs() <= T::epsilon() * (alpha * beta).sqrt()
+                        || alpha.min(beta) <= negligible
+                    {
+                        continue;
+                    }
+                    rotated = true;
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                    let c = T::one() / (T::one() + t * t).sqrt();
+                    let s = c * t;
+                    for i in 0..rows {
+                        let (up, uq) = (u[i][p], u[i][q]);
+                        u[i][p] = c * up - s * uq;
+                        u[i][q] = s * up + c * uq;
+                    }
+                    for i in 0..cols {
+                        let (vp, vq) = (v[i][p], v[i][q]);
+                        v[i][p] = c * vp - s * vq;
+                        v[i][q] = s * vp + c * vq;
+                    }
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+
+        let norms: Vec<T> = (0..cols)
+            .map(|j| {
+                (0..rows)
+                    .fold(T::zero(), |sum, i| sum + u[i][j] * u[i][j])
+                    .sqrt()
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..cols).collect();
+        order.sort_by(|a, b| total_cmp(&norms[*b], &norms[*a]));
+
+        let mut u_sorted: Matrix<T> = Matrix::zero(rows, cols).unwrap();
+        let mut vt: Matrix<T> = Matrix::zero(cols, cols).unwrap();
+        let mut s = Vec::with_capacity(cols);
+        // columns belonging to negligible singular values are pure rounding noise
+        let tol = T::epsilon() * T::from(rows).unwrap() * norms[order[0]];
+        let mut zero_from = cols;
+        for (j, &col) in order.iter().enumerate() {
+            let norm = norms[col];
+            s.push(norm);
+            if norm <= tol {
+                zero_from = zero_from.min(j);
+            } else {
+                for i in 0..rows {
+                    u_sorted[i][j] = u[i][col] / norm;
+                }
+            }
+            for i in 0..cols {
+                vt[j][i] = v[i][col];
+            }
+        }
+        // complete the remaining columns to an orthonormal set
+        for j in zero_from..cols {
+            let (residual, norm) = (0..rows)
+                .map(|k| {
+                    let mut w: Vec<T> = (0..rows)
+                        .map(|i| if i == k { T::one() } else { T::zero() })
+                        .collect();
+                    for c in 0..j {
+                        let dot = u_sorted[k][c];
+                        w.iter_mut()
+                            .enumerate()
+                            .for_each(|(i, x)| *x = *x - dot * u_sorted[i][c]);
+                    }
+                    let norm = w.iter().fold(T::zero(), |sum, x| sum + *x * *x).sqrt();
+                    (w, norm)
+                })
+                .max_by(|(_, a), (_, b)| total_cmp(a, b))
+                .unwrap();
+            for i in 0..rows {
+                u_sorted[i][j] = residual[i] / norm;
+            }
+        }
+        (u_sorted, s, vt, converged)
+    }
+
+    /// Householder QR decomposition without any rank checks.
+    pub(crate) fn householder_qr(&self) -> (Matrix<T>, Matrix<T>) {
+        let rows = self.rows();
+        let cols = self.cols();
+        let two = T::one() + T::one();
+        let mut q: Matrix<T> = Matrix::one(rows).unwrap();
+        let mut r = self.clone();
+
+        for k in 0..(rows - 1).min(cols) {
+            let norm_x = (k..rows)
+                .fold(T::zero(), |sum, i| sum + r[i][k] * r[i][k])
+                .sqrt();
+            if norm_x.is_zero() {
+                continue;
+            }
+            let alpha = if r[k][k] > T::zero() { -norm_x } else { norm_x };
+
+            let mut v: Vec<T> = (k..rows).map(|i| r[i][k]).collect();
+            v[0] = v[0] - alpha;
+            let norm_v = v.iter().fold(T::zero(), |sum, x| sum + *x * *x).sqrt();
+            if norm_v.is_zero() {
+                continue;
+            }
+            v.iter_mut().for_each(|x| *x = *x / norm_v);
+
+            // R = H R
+            for j in k..cols {
+                let dot = (k..rows).fold(T::zero(), |sum, i| sum + v[i - k] * r[i][j]);
+                for i in k..rows {
+                    r[i][j] = r[i][j] - two * v[i - k] * dot;
+                }
+            }
+            // Q = Q H
+            for i in 0..rows {
+                let dot = (k..rows).fold(T::zero(), |sum, j| sum + q[i][j] * v[j - k]);
+                for j in k..rows {
+                    q[i][j] = q[i][j] - two * dot * v[j - k];
+                }
+            }
+
+            r[k][k] = alpha;
+            for i in (k + 1)..rows {
+                r[i][k] = T::zero();
+            }
+        }
+        (q, r)
+    }
+}
+
+/// Compare two floats, ordering `NaN` after all other values so that sorting cannot panic.
+pub(crate) fn total_cmp<T: Float>(a: &T, b: &T) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (false, false) => a.partial_cmp(b).unwrap(),
+        (a_nan, b_nan) => a_nan.cmp(&b_nan),
+    }
+}
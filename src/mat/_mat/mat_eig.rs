@@ -0,0 +1,357 @@
+use super::mat_decomp::total_cmp;
+use crate::err::DimensionError;
+use crate::mat::Matrix;
+use num_traits::Float;
+
+/// Maximum number of sweeps used by the Jacobi eigenvalue method.
+const JACOBI_MAX_SWEEPS: usize = 100;
+/// Maximum number of QR iterations spent on a single eigenvalue.
+const QR_MAX_ITER: usize = 100;
+
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// Calculate the eigenvalues and eigenvectors of a square matrix.
+    ///
+    /// Returns the eigenvalues sorted in descending order and a matrix whose columns are the
+    /// corresponding eigenvectors, normalized to unit length. Symmetric matrices are handled with
+    /// the Jacobi eigenvalue method, general matrices with the shifted QR algorithm.
+    ///
+    /// Non-square matrices result in [`DimensionError::NoSquare`]. Complex eigenvalues are not
+    /// supported and result in [`DimensionError::ComplexEigenvalues`], and if the iteration does
+    /// not converge, which can happen for matrices with non-finite entries,
+    /// [`DimensionError::NoConvergence`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{2.0, 0.0, 0.0; 1.0, 3.0, 0.0; 4.0, -1.0, 5.0};
+    /// let (values, vectors) = mat_a.eig()?;
+    /// assert!((values[0] - 5.0).abs() < 1e-10);
+    /// assert!((values[1] - 3.0).abs() < 1e-10);
+    /// assert!((values[2] - 2.0).abs() < 1e-10);
+    /// assert_eq!(vectors.rows(), 3);
+    ///
+    /// let rotation: Matrix<f64> = matrix!{0.0, -1.0; 1.0, 0.0}; // eigenvalues are ±i
+    /// assert_eq!(rotation.eig(), Err(DimensionError::ComplexEigenvalues));
+    /// # Ok(()) }
+    /// ```
+    pub fn eig(&self) -> Result<(Vec<T>, Matrix<T>), DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        if self.is_symmetric() {
+            let (values, vectors, converged) = self.jacobi_eigen();
+            return if converged {
+                Ok((values, vectors))
+            } else {
+                Err(DimensionError::NoConvergence(JACOBI_MAX_SWEEPS))
+            };
+        }
+
+        let mut values = self.qr_eigenvalues()?;
+        values.sort_by(|a, b| total_cmp(b, a));
+
+        // every eigenvector spans the null space of A - λI, which is given by the right singular
+        // vectors belonging to the smallest singular values
+        let scale = self
+            .matrix
+            .iter()
+            .fold(T::zero(), |max, x| max.max(x.abs()));
+        let tol = T::epsilon().sqrt() * scale.max(T::one());
+        let mut vectors: Matrix<T> = Matrix::zero(dim, dim)?;
+        for (j, value) in values.iter().enumerate() {
+            let multiplicity = values[..j]
+                .iter()
+                .filter(|x| (**x - *value).abs() <= tol)
+                .count();
+            let mut shifted = self.clone();
+            for i in 0..dim {
+                shifted[i][i] = shifted[i][i] - *value;
+            }
+            let (_, _, vt) = shifted.svd()?;
+            let row = dim - 1 - multiplicity.min(dim - 1);
+            for i in 0..dim {
+                vectors[i][j] = vt[row][i];
+            }
+        }
+        Ok((values, vectors))
+    }
+
+    /// Calculate the eigenvalues and orthonormal eigenvectors of a real symmetric matrix using the
+    /// Jacobi eigenvalue method.
+    ///
+    /// Returns the eigenvalues sorted in descending order and a matrix whose columns are the
+    /// corresponding eigenvectors. The matrix has to be symmetric up to a small tolerance relative
+    /// to its largest entry, otherwise [`DimensionError::NoSymmetric`] is returned. If the method
+    /// does not converge within 100 sweeps, which can happen for matrices with non-finite entries,
+    /// [`DimensionError::NoConvergence`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{2.0, 1.0; 1.0, 2.0};
+    /// let (values, vectors) = mat_a.eig_sym()?;
+    /// assert!((values[0] - 3.0).abs() < 1e-12);
+    /// assert!((values[1] - 1.0).abs() < 1e-12);
+    /// assert!((vectors[0][0].abs() - 0.5f64.sqrt()).abs() < 1e-12);
+    ///
+    /// let mat_b: Matrix<f64> = matrix!{1.0, 2.0; 3.0, 4.0};
+    /// assert_eq!(mat_b.eig_sym(), Err(DimensionError::NoSymmetric));
+    /// # Ok(()) }
+    /// ```
+    pub fn eig_sym(&self) -> Result<(Vec<T>, Matrix<T>), DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        let scale = self
+            .matrix
+            .iter()
+            .fold(T::zero(), |max, x| max.max(x.abs()));
+        let tol = T::epsilon().sqrt() * scale;
+        let mut sym = self.clone();
+        let two = T::one() + T::one();
+        for i in 0..dim {
+            for j in 0..i {
+                if (self[i][j] - self[j][i]).abs() > tol {
+                    return Err(DimensionError::NoSymmetric);
+                }
+                let mean = (self[i][j] + self[j][i]) / two;
+                sym[i][j] = mean;
+                sym[j][i] = mean;
+            }
+        }
+        let (values, vectors, converged) = sym.jacobi_eigen();
+        if converged {
+            Ok((values, vectors))
+        } else {
+            Err(DimensionError::NoConvergence(JACOBI_MAX_SWEEPS))
+        }
+    }
+
+    /// Approximate the dominant eigenvalue, the one with the largest absolute value, and its
+    /// eigenvector with the power method.
+    ///
+    /// Returns the eigenvalue and the eigenvector as a column matrix of unit length, once the
+    /// eigenvalue estimate changes by at most `tol` between two iterations and the residual
+    /// `‖Ax - λx‖` is at most `√tol · max(|λ|, 1)`. If that does not happen within `max_iter`
+    /// iterations, e.g. because two eigenvalues of the same absolute value dominate, `None` is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{2.0, 1.0; 1.0, 2.0};
+    /// let (value, vector) = mat_a.power_iteration(1000, 1e-12)?.unwrap();
+    /// assert!((value - 3.0).abs() < 1e-10);
+    /// assert!((vector[0][0] - vector[1][0]).abs() < 1e-5);
+    ///
+    /// let mat_b: Matrix<f64> = matrix!{1.0, 0.0; 0.0, -1.0};
+    /// assert_eq!(mat_b.power_iteration(1000, 1e-12)?, None);
+    /// # Ok(()) }
+    /// ```
+    pub fn power_iteration(
+        &self,
+        max_iter: usize,
+        tol: T,
+    ) -> Result<Option<(T, Matrix<T>)>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        let norm = |v: &[T]| v.iter().fold(T::zero(), |sum, x| sum + *x * *x).sqrt();
+        // a start vector that is not orthogonal to the dominant eigenvector in common cases
+        let mut x: Vec<T> = (1..=dim).map(|i| T::from(i).unwrap()).collect();
+        let x_norm = norm(&x);
+        x.iter_mut().for_each(|v| *v = *v / x_norm);
+        let mut value = T::nan();
+        for _ in 0..max_iter {
+            let y: Vec<T> = (0..dim)
+                .map(|i| (0..dim).fold(T::zero(), |sum, j| sum + self[i][j] * x[j]))
+                .collect();
+            // Rayleigh quotient, x has unit length
+            let next = (0..dim).fold(T::zero(), |sum, i| sum + x[i] * y[i]);
+            let y_norm = norm(&y);
+            if y_norm.is_zero() {
+                // x is in the null space, so it is an eigenvector for 0
+                return Ok(Some((T::zero(), Matrix::from_vec(dim, 1, x)?)));
+            }
+            // the estimate can stall without converging, e.g. for eigenvalues 1 and -1
+            let residual = norm(&(0..dim).map(|i| y[i] - next * x[i]).collect::<Vec<T>>());
+            x = y.into_iter().map(|v| v / y_norm).collect();
+            if (next - value).abs() <= tol && residual <= tol.sqrt() * next.abs().max(T::one()) {
+                return Ok(Some((next, Matrix::from_vec(dim, 1, x)?)));
+            }
+            value = next;
+        }
+        Ok(None)
+    }
+
+    /// Eigenvalues of a general matrix using the QR algorithm with Wilkinson shifts and deflation.
+    ///
+    /// The matrix is reduced to Hessenberg form first, so that a negligible subdiagonal entry
+    /// decouples the trailing block from the rest of the matrix.
+    fn qr_eigenvalues(&self) -> Result<Vec<T>, DimensionError> {
+        let two = T::one() + T::one();
+        let (mut a, _) = self.hessenberg()?;
+        let mut active = self.rows();
+        let mut values = Vec::with_capacity(active);
+        let mut iter = 0;
+
+        while active > 0 {
+            if active == 1 {
+                values.push(a[0][0]);
+                break;
+            }
+            let n = active - 1;
+            let negligible = |a: &Matrix<T>, row: usize| {
+                let scale = (0..active).fold(T::zero(), |sum, j| sum + a[row][j].abs())
+                    + a[row - 1][row - 1].abs();
+                (0..row).all(|j| a[row][j].abs() <= T::epsilon() * scale)
+            };
+
+            if negligible(&a, n) {
+                values.push(a[n][n]);
+                active -= 1;
+                iter = 0;
+                continue;
+            }
+
+            // trailing 2x2 block [[p, q], [r, s]]
+            let (p, q, r, s) = (a[n - 1][n - 1], a[n - 1][n], a[n][n - 1], a[n][n]);
+            let half_tr = (p + s) / two;
+            let disc = ((p - s) / two).powi(2) + q * r;
+
+            if n == 1 || negligible(&a, n - 1) {
+                if disc < T::zero() {
+                    return Err(DimensionError::ComplexEigenvalues);
+                }
+                values.push(half_tr + disc.sqrt());
+                values.push(half_tr - disc.sqrt());
+                active -= 2;
+                iter = 0;
+                continue;
+            }
+
+            iter += 1;
+            if iter > QR_MAX_ITER {
+                return Err(DimensionError::NoConvergence(QR_MAX_ITER));
+            }
+            let shift = if iter % 11 == 0 {
+                // exceptional shift to break cycles
+                s + a[n][n - 1].abs()
+            } else if disc >= T::zero() {
+                let (l1, l2) = (half_tr + disc.sqrt(), half_tr - disc.sqrt());
+                if (l1 - s).abs() < (l2 - s).abs() {
+                    l1
+                } else {
+                    l2
+                }
+            } else {
+                s
+            };
+
+            let mut block: Matrix<T> = Matrix::zero(active, active).unwrap();
+            for i in 0..active {
+                for j in 0..active {
+                    block[i][j] = a[i][j];
+                }
+                block[i][i] = block[i][i] - shift;
+            }
+            let (q, r) = block.householder_qr();
+            for i in 0..active {
+                for j in 0..active {
+                    // RQ is Hessenberg again, entries below the subdiagonal are rounding noise
+                    a[i][j] = if j + 1 < i {
+                        T::zero()
+                    } else {
+                        (0..active).fold(T::zero(), |sum, k| sum + r[i][k] * q[k][j])
+                    };
+                }
+                a[i][i] = a[i][i] + shift;
+            }
+        }
+        Ok(values)
+    }
+
+    /// Eigenvalues and eigenvectors of a symmetric matrix using the cyclic Jacobi method.
+    /// The last value is `false` if the method did not converge within [`JACOBI_MAX_SWEEPS`].
+    fn jacobi_eigen(&self) -> (Vec<T>, Matrix<T>, bool) {
+        let dim = self.rows();
+        let two = T::one() + T::one();
+        let mut a = self.clone();
+        let mut v: Matrix<T> = Matrix::one(dim).unwrap();
+        let norm = a
+            .matrix
+            .iter()
+            .fold(T::zero(), |sum, x| sum + *x * *x)
+            .sqrt();
+
+        let mut converged = false;
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            let off = (0..dim)
+                .map(|i| {
+                    (0..dim)
+                        .filter(|j| *j != i)
+                        .fold(T::zero(), |sum, j| sum + a[i][j] * a[i][j])
+                })
+                .fold(T::zero(), |sum, x| sum + x)
+                .sqrt();
+            if off <= T::epsilon() * norm {
+                converged = true;
+                break;
+            }
+            for p in 0..dim {
+                for q in (p + 1)..dim {
+                    if a[p][q].is_zero() {
+                        continue;
+                    }
+                    let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let c = T::one() / (t * t + T::one()).sqrt();
+                    let s = t * c;
+                    for k in 0..dim {
+                        let (akp, akq) = (a[k][p], a[k][q]);
+                        a[k][p] = c * akp - s * akq;
+                        a[k][q] = s * akp + c * akq;
+                    }
+                    for k in 0..dim {
+                        let (apk, aqk) = (a[p][k], a[q][k]);
+                        a[p][k] = c * apk - s * aqk;
+                        a[q][k] = s * apk + c * aqk;
+                    }
+                    for k in 0..dim {
+                        let (vkp, vkq) = (v[k][p], v[k][q]);
+                        v[k][p] = c * vkp - s * vkq;
+                        v[k][q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+        let mut order: Vec<usize> = (0..dim).collect();
+        order.sort_by(|i, j| total_cmp(&a[*j][*j], &a[*i][*i]));
+        let values = order.iter().map(|i| a[*i][*i]).collect();
+        let mut vectors: Matrix<T> = Matrix::zero(dim, dim).unwrap();
+        for (j, &col) in order.iter().enumerate() {
+            for i in 0..dim {
+                vectors[i][j] = v[i][col];
+            }
+        }
+        (values, vectors, converged)
+    }
+}
@@ -0,0 +1,150 @@
+use super::mat_norm::one_norm;
+use crate::err::DimensionError;
+use crate::mat::{Matrix, PivotMagnitude};
+use num_traits::Float;
+use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+
+/// Largest 1-norms for which the Padé approximants of degree 3, 5, 7, 9 and 13 reach double
+/// precision, from Higham, "The Scaling and Squaring Method for the Matrix Exponential Revisited"
+/// (2005).
+const EXPM_THETA: [f64; 5] = [
+    1.495585217958292e-2,
+    2.53939833006323e-1,
+    9.504178996162932e-1,
+    2.097847961257068e0,
+    5.371920351148152e0,
+];
+
+/// Coefficients of the numerator polynomials of the Padé approximants of degree 3, 5, 7 and 9.
+const PADE_3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+const PADE_5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const PADE_7: [f64; 8] = [
+    17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+];
+const PADE_9: [f64; 10] = [
+    17643225600.0,
+    8821612800.0,
+    2075673600.0,
+    302702400.0,
+    30270240.0,
+    2162160.0,
+    110880.0,
+    3960.0,
+    90.0,
+    1.0,
+];
+/// Coefficients of the numerator polynomial of the Padé approximant of degree 13.
+const PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+
+impl<T> Matrix<T>
+where
+    T: Float + AddAssign + SubAssign + MulAssign + DivAssign + std::iter::Sum + PivotMagnitude,
+{
+    /// Calculate the matrix exponential `e^A` of a square matrix.
+    ///
+    /// Uses the scaling and squaring method with Padé approximants of degree up to 13 from
+    /// Higham, "The Scaling and Squaring Method for the Matrix Exponential Revisited" (2005).
+    /// If the matrix contains non-finite entries, all entries of the result are `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // generator of a rotation by one radian
+    /// let mat_a: Matrix<f64> = matrix!{0.0, -1.0; 1.0, 0.0};
+    /// let rot = mat_a.expm()?;
+    /// assert!((rot[0][0] - 1f64.cos()).abs() < 1e-12);
+    /// assert!((rot[1][0] - 1f64.sin()).abs() < 1e-12);
+    /// # Ok(()) }
+    /// ```
+    pub fn expm(&self) -> Result<Matrix<T>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let norm = one_norm(self.row_iter(), self.cols(), |x: &T| x.abs());
+        let low_degree: [&[f64]; 4] = [&PADE_3, &PADE_5, &PADE_7, &PADE_9];
+        for (theta, coeffs) in EXPM_THETA.iter().zip(low_degree.iter()) {
+            if norm <= T::from(*theta).unwrap() {
+                let (u, v) = self.pade_low(coeffs)?;
+                return self.pade_quotient(u, v);
+            }
+        }
+
+        let theta_13 = T::from(EXPM_THETA[4]).unwrap();
+        let squarings = if norm > theta_13 {
+            (norm / theta_13).log2().ceil().to_i32().unwrap_or(0)
+        } else {
+            0
+        };
+        let two = T::one() + T::one();
+        let scaled = self.clone() * two.powi(-squarings);
+        let (u, v) = scaled.pade_13()?;
+        let mut result = scaled.pade_quotient(u, v)?;
+        for _ in 0..squarings {
+            result = (result.clone() * result)?;
+        }
+        Ok(result)
+    }
+
+    /// Odd and even parts `U` and `V` of the Padé approximant of degree 3, 5, 7 or 9.
+    fn pade_low(&self, coeffs: &[f64]) -> Result<(Matrix<T>, Matrix<T>), DimensionError> {
+        let dim = self.rows();
+        let a2 = (self.clone() * self.clone())?;
+        let mut power: Matrix<T> = Matrix::one(dim)?;
+        let mut u: Matrix<T> = Matrix::zero(dim, dim)?;
+        let mut v: Matrix<T> = Matrix::zero(dim, dim)?;
+        for pair in coeffs.chunks(2) {
+            v += power.clone() * T::from(pair[0]).unwrap();
+            u += power.clone() * T::from(pair[1]).unwrap();
+            power = (power * a2.clone())?;
+        }
+        Ok(((self.clone() * u)?, v))
+    }
+
+    /// Odd and even parts `U` and `V` of the Padé approximant of degree 13.
+    fn pade_13(&self) -> Result<(Matrix<T>, Matrix<T>), DimensionError> {
+        let b = |i: usize| T::from(PADE_13[i]).unwrap();
+        let id: Matrix<T> = Matrix::one(self.rows())?;
+        let a2 = (self.clone() * self.clone())?;
+        let a4 = (a2.clone() * a2.clone())?;
+        let a6 = (a2.clone() * a4.clone())?;
+
+        let u_high = ((a6.clone() * b(13) + a4.clone() * b(11))? + a2.clone() * b(9))?;
+        let u_low =
+            (((a6.clone() * b(7) + a4.clone() * b(5))? + a2.clone() * b(3))? + id.clone() * b(1))?;
+        let u = (self.clone() * ((a6.clone() * u_high)? + u_low)?)?;
+
+        let v_high = ((a6.clone() * b(12) + a4.clone() * b(10))? + a2.clone() * b(8))?;
+        let v_low = (((a6.clone() * b(6) + a4 * b(4))? + a2 * b(2))? + id * b(0))?;
+        let v = ((a6 * v_high)? + v_low)?;
+        Ok((u, v))
+    }
+
+    /// Solve `(V - U) X = V + U` for the Padé approximant `X` of `e^A`.
+    fn pade_quotient(&self, u: Matrix<T>, v: Matrix<T>) -> Result<Matrix<T>, DimensionError> {
+        let denominator = (v.clone() - u.clone())?;
+        let numerator = (v + u)?;
+        match denominator.solve_mat(&numerator)? {
+            Some(result) => Ok(result),
+            None => Ok(Matrix::new(self.rows(), self.cols(), T::nan())?),
+        }
+    }
+}
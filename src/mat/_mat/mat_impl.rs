@@ -1,8 +1,9 @@
 use crate::err::DimensionError;
 use crate::mat::dims::Dimensions;
-use crate::mat::{Matrix, Vector};
-use num_traits::{sign, One, Zero};
-use std::convert::From;
+use crate::mat::{Conjugate, Matrix, MatrixView, MatrixViewMut, PivotMagnitude, Vector};
+use num_traits::{Float, Num, One, ToPrimitive, Zero};
+use std::convert::{From, TryFrom};
+use std::ops::{AddAssign, Bound, Mul, MulAssign, Neg, RangeBounds};
 
 impl<T> Matrix<T>
 where
@@ -42,56 +43,6 @@ where
         }
     }
 
-    /// Create a new matrix from a vec.
-    ///
-    /// # Arguments
-    ///
-    /// * `rows` - Row count of matrix
-    /// * `cols` - Column count of matrix
-    /// * `vec` - Vector of length `rows x cols` where `vec[i * cols + j]` is the entry in row `i` and column `j`
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use libmat::mat::Matrix;
-    /// # use libmat::matrix;
-    /// let mat = matrix!{1, 2, 3; 3, 2, 1; 2, 1, 3};
-    /// println!("{}", mat);
-    ///
-    /// // Output:
-    /// // 1 2 3
-    /// // 3 2 1
-    /// // 2 1 3
-    /// ```
-    pub fn from_vec(rows: usize, cols: usize, vec: Vec<T>) -> Result<Matrix<T>, DimensionError> {
-        if vec.len() != rows * cols {
-            Err(DimensionError::InvalidInputDimensions(
-                vec.len(),
-                rows * cols,
-            ))
-        } else {
-            Ok(Matrix::<T> {
-                dims: Dimensions::new(rows, cols),
-                matrix: vec,
-            })
-        }
-    }
-
-    // pub fn insert_row(&mut self, at: usize, row: &[T]) -> Result<(), MatrixError> {
-    //     if row.len() != self[0].len() {
-    //         Err(MatrixError::IndexOutOfBounds)
-    //     } else if at * self.cols() >= self.matrix.len() {
-    //         Err(MatrixError::IndexOutOfBounds)
-    //     } else {
-    //         for i in 0..row.len() {
-    //             self.matrix.insert(at * self.cols() + i, row[i]);
-    //         }
-    //         Ok(())
-    //     }
-    // }
-
-    // pub fn insert_col() {}
-
     /// Create an identity matrix of type `T` with dimensions `dim x dim`.
     ///
     /// # Arguments
@@ -121,6 +72,26 @@ where
         Ok(res)
     }
 
+    /// Create an identity matrix of type `T` with dimensions `dim x dim`. This is the same as
+    /// [`one`](Matrix::one), so a dimension of zero results in
+    /// [`DimensionError::InvalidDimensions`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<i32> = Matrix::identity(2)?;
+    /// assert_eq!(mat_a, matrix!{1, 0; 0, 1});
+    /// assert!(Matrix::<i32>::identity(0).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn identity(dim: usize) -> Result<Matrix<T>, DimensionError> {
+        Matrix::one(dim)
+    }
+
     /// Create a zero-matrix of type `T`.
     ///
     /// # Arguments
@@ -179,9 +150,123 @@ where
         }
         Ok(res_mat)
     }
+
+    /// Creates a square diagonal matrix with the entries of `diag` on its main diagonal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// assert_eq!(Matrix::from_diag(&[1, 2, 3])?, matrix!{1, 0, 0; 0, 2, 0; 0, 0, 3});
+    /// # Ok(()) }
+    /// ```
+    pub fn from_diag(diag: &[T]) -> Result<Matrix<T>, DimensionError> {
+        Matrix::diag_with(diag.len(), diag)
+    }
+
+    /// Creates a square matrix with the entries of `diag` on the `k`-th diagonal and zeros
+    /// elsewhere. `k > 0` refers to the diagonals above the main diagonal, `k < 0` to the ones
+    /// below. The matrix has dimensions `n x n` with `n = diag.len() + |k|`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// assert_eq!(Matrix::from_diag_offset(&[1, 2], 1)?, matrix!{0, 1, 0; 0, 0, 2; 0, 0, 0});
+    /// assert_eq!(Matrix::from_diag_offset(&[1, 2], -1)?, matrix!{0, 0, 0; 1, 0, 0; 0, 2, 0});
+    /// # Ok(()) }
+    /// ```
+    pub fn from_diag_offset(diag: &[T], k: isize) -> Result<Matrix<T>, DimensionError> {
+        let offset = k.unsigned_abs();
+        let dim = diag.len() + offset;
+        let mut res_mat = Matrix::zero(dim, dim)?;
+        for (i, x) in diag.iter().enumerate() {
+            if k >= 0 {
+                res_mat[i][i + offset] = x.clone();
+            } else {
+                res_mat[i + offset][i] = x.clone();
+            }
+        }
+        Ok(res_mat)
+    }
+
+    /// Construct the Vandermonde matrix of `nodes` with `degree + 1` columns, i.e. the matrix
+    /// whose entry `(i, j)` is `nodes[i]` raised to the power `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nodes` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert_eq!(Matrix::vandermonde(&[1, 2, 3], 2), matrix!{1, 1, 1; 1, 2, 4; 1, 3, 9});
+    /// ```
+    pub fn vandermonde(nodes: &[T], degree: usize) -> Matrix<T> {
+        let mut entries = Vec::with_capacity(nodes.len() * (degree + 1));
+        for x in nodes {
+            let mut power = T::one();
+            for _ in 0..degree {
+                let next = power.clone() * x.clone();
+                entries.push(power);
+                power = next;
+            }
+            entries.push(power);
+        }
+        Matrix {
+            dims: Dimensions::new(nodes.len(), degree + 1),
+            matrix: entries,
+        }
+    }
+
+    /// Construct the companion matrix of the monic polynomial
+    /// `x^n + c[n-1] x^(n-1) + ... + c[1] x + c[0]`, where `coeffs` are the coefficients
+    /// `c[0], ..., c[n-1]` in ascending order. The eigenvalues of the companion matrix are the
+    /// roots of the polynomial.
+    ///
+    /// An empty slice results in [`DimensionError::InvalidDimensions`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // (x - 1)(x - 2) = x^2 - 3x + 2
+    /// let mat: Matrix<f64> = Matrix::companion(&[2.0, -3.0])?;
+    /// assert_eq!(mat, matrix!{0.0, -2.0; 1.0, 3.0});
+    /// let (roots, _) = mat.eig()?;
+    /// assert!((roots[0] - 2.0).abs() < 1e-12 && (roots[1] - 1.0).abs() < 1e-12);
+    /// # Ok(()) }
+    /// ```
+    pub fn companion(coeffs: &[T]) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Neg<Output = T>,
+    {
+        let dim = coeffs.len();
+        let mut mat = Matrix::zero(dim, dim)?;
+        for i in 1..dim {
+            mat[i][i - 1] = T::one();
+        }
+        for (i, c) in coeffs.iter().enumerate() {
+            mat[i][dim - 1] = -c.clone();
+        }
+        Ok(mat)
+    }
+
+    #[allow(clippy::type_complexity)]
     pub fn lupdecompose(&self) -> Result<Option<(Matrix<T>, Vec<usize>)>, DimensionError>
     where
-        T: sign::Signed + PartialOrd + Clone + Zero + One + std::iter::Sum,
+        T: Num + PivotMagnitude + std::iter::Sum,
     {
         if !self.is_square() {
             Err(DimensionError::NoSquare)
@@ -189,16 +274,17 @@ where
             let mut a: Matrix<T> = self.clone();
             let dim = self.rows();
             let mut imax: usize;
-            let mut max_a: T;
+            let mut max_a: T::Magnitude;
             let mut p: Vec<usize> = (0..=dim).collect();
 
             for i in 0..dim {
-                max_a = T::zero();
+                max_a = Zero::zero();
                 imax = i;
 
                 for k in i..dim {
-                    if a[i][k].abs() > max_a {
-                        max_a = a[i][k].abs();
+                    let magnitude = a[i][k].pivot_magnitude();
+                    if magnitude > max_a {
+                        max_a = magnitude;
                         imax = k;
                     }
                 }
@@ -234,6 +320,9 @@ where
 
     /// Calculate the determinant of a square matrix.
     ///
+    /// The determinant is calculated from the LUP decomposition of the matrix, so for
+    /// singular matrices zero is returned. Non-square matrices result in an error.
+    ///
     /// # Example
     ///
     /// ```
@@ -241,13 +330,14 @@ where
     /// # use libmat::matrix;
     /// # use libmat::err::DimensionError;
     /// # fn main() -> Result<(), DimensionError> {
-    /// let mat = matrix!{1.0, 2.0, 3.0; 3.0, 2.0, 1.0; 2.0, 1.0, 3.0};
-    /// assert_eq!(mat.det()?, -12.0);
+    /// let mat: Matrix<f64> = matrix!{1.0, 2.0, 3.0; 3.0, 2.0, 1.0; 2.0, 1.0, 3.0};
+    /// assert!((mat.det()? + 12.0).abs() < 1e-12);
+    /// assert_eq!(Matrix::new(2, 3, 1.0)?.det(), Err(DimensionError::NoSquare));
     /// # Ok(()) }
     /// ```
     pub fn det(&self) -> Result<T, DimensionError>
     where
-        T: sign::Signed + PartialOrd + std::iter::Sum,
+        T: Num + Neg<Output = T> + PivotMagnitude + std::iter::Sum,
     {
         if let Some((mat, p)) = self.lupdecompose()? {
             let mut det = mat.matrix[0].clone();
@@ -263,25 +353,164 @@ where
             Ok(T::zero())
         }
     }
+    /// Raise a square matrix to the power `exp` using exponentiation by squaring.
+    ///
+    /// `pow(0)` returns the identity matrix. Non-square matrices result in an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let fib = matrix!{1, 1; 1, 0};
+    /// assert_eq!(fib.pow(10)?, matrix!{89, 55; 55, 34});
+    /// assert_eq!(fib.pow(0)?, Matrix::one(2)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn pow(&self, exp: u32) -> Result<Matrix<T>, DimensionError>
+    where
+        T: std::iter::Sum,
+    {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        self.pow_by_squaring(exp.into())
+    }
 
-    /// Returns true if the matrix is a square matrix, false otherwise.
+    /// Raise a square matrix to a signed power. Negative exponents raise the inverse of the matrix,
+    /// so `None` is returned if the matrix is singular and `exp < 0`.
     ///
     /// # Example
     ///
     /// ```
     /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
     /// # use libmat::err::DimensionError;
     /// # fn main() -> Result<(), DimensionError> {
-    /// let mat_a: Matrix<i32> = Matrix::one(3)?;
-    /// let mat_b: Matrix<f32> = Matrix::zero(3, 4)?;
-    /// assert_eq!(mat_a.is_square(), true);
-    /// assert_eq!(mat_b.is_square(), false);
+    /// let mat: Matrix<f64> = matrix!{2.0, 0.0; 0.0, 4.0};
+    /// assert_eq!(mat.powi(-2)?, Some(matrix!{0.25, 0.0; 0.0, 0.0625}));
+    /// assert_eq!(matrix!{1.0, 2.0; 2.0, 4.0}.powi(-1)?, None);
     /// # Ok(()) }
     /// ```
-    pub fn is_square(&self) -> bool {
-        self.dims.is_square()
+    pub fn powi(&self, exp: i64) -> Result<Option<Matrix<T>>, DimensionError>
+    where
+        T: Num + PivotMagnitude + std::iter::Sum,
+    {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let base = if exp < 0 {
+            match self.solve_mat(&Matrix::one(self.rows())?)? {
+                Some(inv) => inv,
+                None => return Ok(None),
+            }
+        } else {
+            self.clone()
+        };
+        Ok(Some(base.pow_by_squaring(exp.unsigned_abs())?))
+    }
+
+    /// Exponentiation by squaring for a square matrix.
+    fn pow_by_squaring(&self, exp: u64) -> Result<Matrix<T>, DimensionError>
+    where
+        T: std::iter::Sum,
+    {
+        let mut result: Option<Matrix<T>> = None;
+        let mut base = self.clone();
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = Some(match result {
+                    Some(res) => (res * base.clone())?,
+                    None => base.clone(),
+                });
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = (base.clone() * base)?;
+            }
+        }
+        match result {
+            Some(res) => Ok(res),
+            None => Matrix::one(self.rows()),
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// Construct the `n x n` Hilbert matrix, whose entry `(i, j)` is `1 / (i + j + 1)`. Hilbert
+    /// matrices are notoriously ill-conditioned, which makes them a common test for numerical
+    /// algorithms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat: Matrix<f64> = Matrix::hilbert(2);
+    /// assert_eq!(mat, matrix!{1.0, 0.5; 0.5, 1.0 / 3.0});
+    /// ```
+    pub fn hilbert(n: usize) -> Matrix<T> {
+        let mut entries = Vec::with_capacity(n * n);
+        for i in 0..n {
+            for j in 0..n {
+                entries.push(T::one() / float(i + j + 1));
+            }
+        }
+        Matrix {
+            dims: Dimensions::new(n, n),
+            matrix: entries,
+        }
+    }
+
+    /// Construct the inverse of the `n x n` Hilbert matrix from its closed form. All entries are
+    /// integers, so they are exact as long as they can be represented by `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat: Matrix<f64> = Matrix::hilbert_inv(3);
+    /// assert_eq!(mat, matrix!{9.0, -36.0, 30.0; -36.0, 192.0, -180.0; 30.0, -180.0, 180.0});
+    /// ```
+    pub fn hilbert_inv(n: usize) -> Matrix<T> {
+        let mut entries = Vec::with_capacity(n * n);
+        for i in 0..n {
+            for j in 0..n {
+                let binom = binomial::<T>(i + j, i);
+                let entry = float::<T>(i + j + 1)
+                    * binomial(n + i, n - j - 1)
+                    * binomial(n + j, n - i - 1)
+                    * binom
+                    * binom;
+                entries.push(if (i + j) % 2 == 0 { entry } else { -entry });
+            }
+        }
+        Matrix {
+            dims: Dimensions::new(n, n),
+            matrix: entries,
+        }
     }
+}
 
+impl<T> Matrix<T>
+where
+    T: Clone,
+{
     /// Transpose a matrix.
     ///
     /// # Example
@@ -299,6 +528,7 @@ where
     /// // 3 7 11
     /// // 4 8 12
     /// assert_eq!(mat_a.transpose(), mat_b);
+    /// assert_eq!(matrix!{{1, 2, 3}, {4, 5, 6}}.transpose(), matrix!{{1, 4}, {2, 5}, {3, 6}});
     /// ```
     pub fn transpose(&self) -> Matrix<T> {
         let mut vec = Vec::<T>::new();
@@ -309,46 +539,1698 @@ where
         }
         Matrix::<T>::from_vec(self.cols(), self.rows(), vec).unwrap()
     }
-}
-
-impl<T> From<Vector<T>> for Matrix<T>
-where
-    T: Zero + One + Clone,
-{
-    fn from(v: Vector<T>) -> Matrix<T> {
-        if v.is_row_vector() {
-            Matrix::<T>::from_vec(1, v.size(), v.entries).unwrap()
-        } else {
-            Matrix::<T>::from_vec(v.size(), 1, v.entries).unwrap()
-        }
-    }
-}
 
-// GETTERS
-impl<T> Matrix<T> {
-    /// Get the number of rows
-    pub fn rows(&self) -> usize {
-        self.dims.rows()
+    /// Transpose the matrix and conjugate every entry. For real entry types this is the same as
+    /// [`transpose`](Matrix::transpose).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat = matrix!{1.0, 2.0; 3.0, 4.0};
+    /// assert_eq!(mat.conjugate_transpose(), mat.transpose());
+    /// ```
+    #[doc(alias = "adjoint")]
+    pub fn conjugate_transpose(&self) -> Matrix<T>
+    where
+        T: Conjugate,
+    {
+        let mut mat = self.transpose();
+        mat.matrix.iter_mut().for_each(|x| *x = x.conjugate());
+        mat
     }
 
-    /// Get the number of columns
-    pub fn cols(&self) -> usize {
-        self.dims.cols()
-    }
+    /// Calculate the rank of a matrix using Gaussian elimination with partial pivoting.
+    ///
+    /// The elimination is done on `f64` copies of the entries. Pivots that are smaller than a
+    /// tolerance relative to the largest entry of the matrix are treated as zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2, 3, 4, 5; 2, 4, 6, 8, 10; 0, 1, 0, 1, 0};
+    /// assert_eq!(mat_a.rank(), 2);
+    /// assert_eq!(Matrix::<f64>::zero(3, 3)?.rank(), 0);
+    /// # Ok(()) }
+    /// ```
+    pub fn rank(&self) -> usize
+    where
+        T: ToPrimitive,
+    {
+        let rows = self.rows();
+        let cols = self.cols();
+        let mut a: Vec<f64> = self
+            .matrix
+            .iter()
+            .map(|x| x.to_f64().unwrap_or(f64::NAN))
+            .collect();
+        let max_abs = a.iter().fold(0.0_f64, |max, x| max.max(x.abs()));
+        let tol = rows.max(cols) as f64 * f64::EPSILON * max_abs;
 
-    pub fn dims(&self) -> Dimensions {
-        Dimensions::new(self.rows(), self.cols())
+        let mut rank = 0;
+        for col in 0..cols {
+            if rank == rows {
+                break;
+            }
+            let (pivot, max) = (rank..rows).map(|i| (i, a[i * cols + col].abs())).fold(
+                (rank, 0.0),
+                |(imax, max), (i, x)| {
+                    if x > max {
+                        (i, x)
+                    } else {
+                        (imax, max)
+                    }
+                },
+            );
+            if max <= tol {
+                continue;
+            }
+            for j in 0..cols {
+                a.swap(rank * cols + j, pivot * cols + j);
+            }
+            for i in (rank + 1)..rows {
+                let factor = a[i * cols + col] / a[rank * cols + col];
+                for j in col..cols {
+                    a[i * cols + j] -= factor * a[rank * cols + j];
+                }
+            }
+            rank += 1;
+        }
+        rank
     }
 
-    pub fn entry(&self, i: impl Into<usize>, j: impl Into<usize>) -> T
-    where
-        T: Clone,
-    {
-        self.matrix[self.cols() * i.into() + j.into()].clone()
+    /// Copy the entries of the main diagonal, which has `min(rows, cols)` entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// assert_eq!(matrix!{1, 2, 3; 4, 5, 6}.diagonal(), vec![1, 5]);
+    /// # Ok(()) }
+    /// ```
+    pub fn diagonal(&self) -> Vec<T> {
+        (0..self.rows().min(self.cols()))
+            .map(|i| self.matrix[i * self.cols() + i].clone())
+            .collect()
     }
 
-    pub fn entry_mut(&mut self, i: impl Into<usize>, j: impl Into<usize>) -> &mut T {
-        let cols = self.cols();
+    /// Calculate the trace of a square matrix, which is the sum of its diagonal entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// assert_eq!(matrix!{{1, 2}, {3, 4}}.trace()?, 5);
+    /// assert_eq!(matrix!{{1, 2, 3}, {4, 5, 6}}.trace(), Err(DimensionError::NoSquare));
+    /// # Ok(()) }
+    /// ```
+    pub fn trace(&self) -> Result<T, DimensionError>
+    where
+        T: Zero,
+    {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        Ok((0..self.rows()).fold(T::zero(), |sum, i| {
+            sum + self.matrix[i * self.cols() + i].clone()
+        }))
+    }
+
+    /// Calculate the Frobenius inner product `tr(AᵀB)`, the sum of the products of all entries at
+    /// the same position. Both matrices need to have the same dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4};
+    /// let mat_b = matrix!{2, 0; -1, 1};
+    /// assert_eq!(mat_a.frobenius_inner(&mat_b)?, 3);
+    /// # Ok(()) }
+    /// ```
+    pub fn frobenius_inner(&self, other: &Matrix<T>) -> Result<T, DimensionError>
+    where
+        T: Zero + std::ops::Mul<Output = T>,
+    {
+        if self.dims != other.dims {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                other.dims,
+                "take the inner product of".to_owned(),
+            ));
+        }
+        Ok(self
+            .matrix
+            .iter()
+            .zip(other.matrix.iter())
+            .fold(T::zero(), |sum, (a, b)| sum + a.clone() * b.clone()))
+    }
+
+    /// Calculate the Kronecker product of two matrices. For an `m x n` matrix `A` and a `p x q`
+    /// matrix `B` the result is the `mp x nq` block matrix whose block `(i, j)` is `A[i][j] * B`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4};
+    /// let mat_b = matrix!{0, 1; 1, 0};
+    /// assert_eq!(
+    ///     mat_a.kronecker(&mat_b),
+    ///     matrix!{0, 1, 0, 2; 1, 0, 2, 0; 0, 3, 0, 4; 3, 0, 4, 0}
+    /// );
+    /// # Ok(()) }
+    /// ```
+    pub fn kronecker(&self, rhs: &Matrix<T>) -> Matrix<T>
+    where
+        T: std::ops::Mul<Output = T>,
+    {
+        let rows = self.rows() * rhs.rows();
+        let cols = self.cols() * rhs.cols();
+        let mut entries = Vec::with_capacity(rows * cols);
+        for i in 0..self.rows() {
+            for k in 0..rhs.rows() {
+                for j in 0..self.cols() {
+                    for l in 0..rhs.cols() {
+                        entries.push(self[i][j].clone() * rhs[k][l].clone());
+                    }
+                }
+            }
+        }
+        Matrix {
+            dims: Dimensions::new(rows, cols),
+            matrix: entries,
+        }
+    }
+
+    /// Calculate the outer product of two vectors. For `a` of length `m` and `b` of length `n`
+    /// the result is the `m x n` matrix with the entries `a[i] * b[j]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert_eq!(Matrix::outer(&[1, 2], &[3, 4, 5]), matrix!{3, 4, 5; 6, 8, 10});
+    /// ```
+    #[doc(alias = "outer_product")]
+    pub fn outer(a: &[T], b: &[T]) -> Matrix<T>
+    where
+        T: Mul<Output = T>,
+    {
+        Matrix {
+            dims: Dimensions::new(a.len(), b.len()),
+            matrix: a
+                .iter()
+                .flat_map(|x| b.iter().map(move |y| x.clone() * y.clone()))
+                .collect(),
+        }
+    }
+
+    /// Construct the Toeplitz matrix with the first column `first_col` and the first row
+    /// `first_row`, i.e. the matrix whose entry `(i, j)` is `first_col[i - j]` for `i >= j` and
+    /// `first_row[j - i]` for `j > i`.
+    ///
+    /// Both slices need to start with the same entry, otherwise
+    /// [`DimensionError::InconsistentCorner`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = Matrix::toeplitz(&[1, 2, 3], &[1, 4, 5, 6])?;
+    /// assert_eq!(mat, matrix!{1, 4, 5, 6; 2, 1, 4, 5; 3, 2, 1, 4});
+    /// # Ok(()) }
+    /// ```
+    pub fn toeplitz(first_col: &[T], first_row: &[T]) -> Result<Matrix<T>, DimensionError>
+    where
+        T: PartialEq,
+    {
+        match (first_col.first(), first_row.first()) {
+            (None, _) | (_, None) => return Err(DimensionError::InvalidDimensions),
+            (Some(a), Some(b)) if a != b => return Err(DimensionError::InconsistentCorner),
+            _ => {}
+        }
+        Matrix::from_fn(first_col.len(), first_row.len(), |i, j| {
+            if i >= j {
+                first_col[i - j].clone()
+            } else {
+                first_row[j - i].clone()
+            }
+        })
+    }
+
+    /// Copy the block of the matrix spanned by the row range `rows` and the column range `cols`
+    /// into a new matrix. Any kind of range is accepted, e.g. `..`, `a..b` or `a..=b`.
+    ///
+    /// Empty ranges result in [`DimensionError::InvalidDimensions`], ranges reaching past the last
+    /// row or column in [`DimensionError::OutOfBounds`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9};
+    /// assert_eq!(mat.submatrix(1.., 0..2)?, matrix!{4, 5; 7, 8});
+    /// assert_eq!(mat.submatrix(..2, ..)?, matrix!{1, 2, 3; 4, 5, 6});
+    /// assert_eq!(mat.submatrix(0..=3, 0..1), Err(DimensionError::OutOfBounds(3, 3)));
+    /// # Ok(()) }
+    /// ```
+    pub fn submatrix<R, C>(&self, rows: R, cols: C) -> Result<Matrix<T>, DimensionError>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (row_start, row_end) = resolve_range(&rows, self.rows())?;
+        let (col_start, col_end) = resolve_range(&cols, self.cols())?;
+        let mut entries = Vec::with_capacity((row_end - row_start) * (col_end - col_start));
+        for i in row_start..row_end {
+            entries.extend_from_slice(&self[i][col_start..col_end]);
+        }
+        Ok(Matrix {
+            dims: Dimensions::new(row_end - row_start, col_end - col_start),
+            matrix: entries,
+        })
+    }
+
+    /// Overwrite the block of the matrix starting at row `row_off` and column `col_off` with the
+    /// entries of `block`. If `block` does not fit into the matrix at that position,
+    /// [`DimensionError::OutOfBounds`] is returned and the matrix is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat = Matrix::zero(3, 3)?;
+    /// mat.set_submatrix(1, 1, &matrix!{1, 2; 3, 4})?;
+    /// assert_eq!(mat, matrix!{0, 0, 0; 0, 1, 2; 0, 3, 4});
+    /// # Ok(()) }
+    /// ```
+    pub fn set_submatrix(
+        &mut self,
+        row_off: usize,
+        col_off: usize,
+        block: &Matrix<T>,
+    ) -> Result<(), DimensionError> {
+        if row_off + block.rows() > self.rows() {
+            return Err(DimensionError::OutOfBounds(
+                row_off + block.rows() - 1,
+                self.rows(),
+            ));
+        }
+        if col_off + block.cols() > self.cols() {
+            return Err(DimensionError::OutOfBounds(
+                col_off + block.cols() - 1,
+                self.cols(),
+            ));
+        }
+        for (i, row) in block.row_iter().enumerate() {
+            self[row_off + i][col_off..col_off + block.cols()].clone_from_slice(row);
+        }
+        Ok(())
+    }
+
+    /// Return a copy of the matrix with row `row` removed.
+    ///
+    /// Indices that are out of bounds result in [`DimensionError::OutOfBounds`], removing the only
+    /// row of a matrix in [`DimensionError::InvalidDimensions`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9};
+    /// assert_eq!(mat.delete_row(1)?, matrix!{1, 2, 3; 7, 8, 9});
+    /// # Ok(()) }
+    /// ```
+    pub fn delete_row(&self, row: usize) -> Result<Matrix<T>, DimensionError> {
+        if row >= self.rows() {
+            return Err(DimensionError::OutOfBounds(row, self.rows()));
+        }
+        if self.rows() == 1 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        let mut entries = Vec::with_capacity(self.matrix.len() - self.cols());
+        entries.extend_from_slice(&self.matrix[..row * self.cols()]);
+        entries.extend_from_slice(&self.matrix[(row + 1) * self.cols()..]);
+        Ok(Matrix {
+            dims: Dimensions::new(self.rows() - 1, self.cols()),
+            matrix: entries,
+        })
+    }
+
+    /// Return a copy of the matrix with column `col` removed.
+    ///
+    /// Indices that are out of bounds result in [`DimensionError::OutOfBounds`], removing the only
+    /// column of a matrix in [`DimensionError::InvalidDimensions`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9};
+    /// assert_eq!(mat.delete_col(0)?, matrix!{2, 3; 5, 6; 8, 9});
+    /// // the minor of entry (0, 0)
+    /// assert_eq!(mat.delete_row(0)?.delete_col(0)?, matrix!{5, 6; 8, 9});
+    /// # Ok(()) }
+    /// ```
+    pub fn delete_col(&self, col: usize) -> Result<Matrix<T>, DimensionError> {
+        if col >= self.cols() {
+            return Err(DimensionError::OutOfBounds(col, self.cols()));
+        }
+        if self.cols() == 1 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        let entries = self
+            .matrix
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| idx % self.cols() != col)
+            .map(|(_, x)| x.clone())
+            .collect();
+        Ok(Matrix {
+            dims: Dimensions::new(self.rows(), self.cols() - 1),
+            matrix: entries,
+        })
+    }
+
+    /// Return a copy of the matrix with `data` inserted as a new row before row `row`. An index
+    /// equal to the number of rows appends the new row.
+    ///
+    /// `data` needs to have one entry per column, otherwise
+    /// [`DimensionError::InvalidInputDimensions`] is returned. Indices larger than the number of rows
+    /// result in [`DimensionError::OutOfBounds`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 2; 5, 6};
+    /// assert_eq!(mat.insert_row(1, vec![3, 4])?, matrix!{1, 2; 3, 4; 5, 6});
+    /// assert_eq!(mat.insert_row(2, vec![7, 8])?, matrix!{1, 2; 5, 6; 7, 8});
+    /// # Ok(()) }
+    /// ```
+    pub fn insert_row(&self, row: usize, data: Vec<T>) -> Result<Matrix<T>, DimensionError> {
+        if data.len() != self.cols() {
+            return Err(DimensionError::InvalidInputDimensions(
+                data.len(),
+                self.cols(),
+            ));
+        }
+        if row > self.rows() {
+            return Err(DimensionError::OutOfBounds(row, self.rows()));
+        }
+        let mut entries = Vec::with_capacity(self.matrix.len() + data.len());
+        entries.extend_from_slice(&self.matrix[..row * self.cols()]);
+        entries.extend(data);
+        entries.extend_from_slice(&self.matrix[row * self.cols()..]);
+        Ok(Matrix {
+            dims: Dimensions::new(self.rows() + 1, self.cols()),
+            matrix: entries,
+        })
+    }
+
+    /// Return a copy of the matrix with `data` inserted as a new column before column `col`. An
+    /// index equal to the number of columns appends the new column.
+    ///
+    /// `data` needs to have one entry per row, otherwise
+    /// [`DimensionError::InvalidInputDimensions`] is returned. Indices larger than the number of
+    /// columns result in [`DimensionError::OutOfBounds`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 3; 4, 6};
+    /// assert_eq!(mat.insert_col(1, vec![2, 5])?, matrix!{1, 2, 3; 4, 5, 6});
+    /// assert_eq!(mat.insert_col(0, vec![0, 0])?, matrix!{0, 1, 3; 0, 4, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn insert_col(&self, col: usize, data: Vec<T>) -> Result<Matrix<T>, DimensionError> {
+        if data.len() != self.rows() {
+            return Err(DimensionError::InvalidInputDimensions(
+                data.len(),
+                self.rows(),
+            ));
+        }
+        if col > self.cols() {
+            return Err(DimensionError::OutOfBounds(col, self.cols()));
+        }
+        let mut entries = Vec::with_capacity(self.matrix.len() + data.len());
+        for (i, x) in data.into_iter().enumerate() {
+            entries.extend_from_slice(&self[i][..col]);
+            entries.push(x);
+            entries.extend_from_slice(&self[i][col..]);
+        }
+        Ok(Matrix {
+            dims: Dimensions::new(self.rows(), self.cols() + 1),
+            matrix: entries,
+        })
+    }
+
+    /// Insert `row` as a new row before row `at` in place. An index equal to the number of rows
+    /// appends the new row. See [`insert_row`](Matrix::insert_row) for a copying version.
+    ///
+    /// `row` needs to have one entry per column, otherwise
+    /// [`DimensionError::InvalidInputDimensions`] is returned. Indices larger than the number of rows
+    /// result in [`DimensionError::OutOfBounds`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat = matrix!{1, 2; 5, 6};
+    /// mat.insert_row_mut(1, &[3, 4])?;
+    /// mat.insert_row_mut(3, &[7, 8])?;
+    /// assert_eq!(mat, matrix!{1, 2; 3, 4; 5, 6; 7, 8});
+    /// # Ok(()) }
+    /// ```
+    pub fn insert_row_mut(&mut self, at: usize, row: &[T]) -> Result<(), DimensionError> {
+        if row.len() != self.cols() {
+            return Err(DimensionError::InvalidInputDimensions(
+                row.len(),
+                self.cols(),
+            ));
+        }
+        if at > self.rows() {
+            return Err(DimensionError::OutOfBounds(at, self.rows()));
+        }
+        let idx = at * self.cols();
+        self.matrix.splice(idx..idx, row.iter().cloned());
+        self.dims = Dimensions::new(self.rows() + 1, self.cols());
+        Ok(())
+    }
+
+    /// Insert `col` as a new column before column `at` in place. An index equal to the number of
+    /// columns appends the new column. See [`insert_col`](Matrix::insert_col) for a copying version.
+    ///
+    /// `col` needs to have one entry per row, otherwise
+    /// [`DimensionError::InvalidInputDimensions`] is returned. Indices larger than the number of
+    /// columns result in [`DimensionError::OutOfBounds`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat = matrix!{1, 3; 4, 6};
+    /// mat.insert_col_mut(1, &[2, 5])?;
+    /// assert_eq!(mat, matrix!{1, 2, 3; 4, 5, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn insert_col_mut(&mut self, at: usize, col: &[T]) -> Result<(), DimensionError> {
+        if col.len() != self.rows() {
+            return Err(DimensionError::InvalidInputDimensions(
+                col.len(),
+                self.rows(),
+            ));
+        }
+        if at > self.cols() {
+            return Err(DimensionError::OutOfBounds(at, self.cols()));
+        }
+        let cols = self.cols();
+        let mut entries = Vec::with_capacity(self.matrix.len() + col.len());
+        for (row, x) in self.matrix.chunks(cols).zip(col.iter()) {
+            entries.extend_from_slice(&row[..at]);
+            entries.push(x.clone());
+            entries.extend_from_slice(&row[at..]);
+        }
+        self.matrix = entries;
+        self.dims = Dimensions::new(self.rows(), cols + 1);
+        Ok(())
+    }
+
+    /// Copy the matrix into a `rows x cols` matrix with the same entries in row-major order. See
+    /// [`Matrix::into_reshaped`] for a version that does not copy.
+    pub fn reshape(&self, rows: usize, cols: usize) -> Result<Matrix<T>, DimensionError> {
+        self.clone().into_reshaped(rows, cols)
+    }
+
+    /// Concatenate two matrices horizontally, placing `other` to the right of `self`. Both matrices
+    /// need to have the same number of rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4};
+    /// let mat_b = matrix!{5; 6};
+    /// assert_eq!(mat_a.hstack(&mat_b)?, matrix!{1, 2, 5; 3, 4, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn hstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, DimensionError> {
+        Matrix::hconcat(&[self, other])
+    }
+
+    /// Concatenate any number of matrices horizontally, from left to right. All matrices need to
+    /// have the same number of rows and at least one matrix is required.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1; 4};
+    /// let mat_b = matrix!{2, 3; 5, 6};
+    /// assert_eq!(Matrix::hconcat(&[&mat_a, &mat_b, &mat_a])?, matrix!{1, 2, 3, 1; 4, 5, 6, 4});
+    /// # Ok(()) }
+    /// ```
+    pub fn hconcat(mats: &[&Matrix<T>]) -> Result<Matrix<T>, DimensionError> {
+        let first = mats.first().ok_or(DimensionError::InvalidDimensions)?;
+        if let Some(mat) = mats.iter().find(|mat| mat.rows() != first.rows()) {
+            return Err(DimensionError::NoMatch(
+                first.dims,
+                mat.dims,
+                "stack horizontally".to_owned(),
+            ));
+        }
+        let cols = mats.iter().map(|mat| mat.cols()).sum();
+        let mut entries = Vec::with_capacity(first.rows() * cols);
+        for i in 0..first.rows() {
+            for mat in mats {
+                entries.extend_from_slice(&mat[i]);
+            }
+        }
+        Ok(Matrix {
+            dims: Dimensions::new(first.rows(), cols),
+            matrix: entries,
+        })
+    }
+
+    /// Concatenate any number of matrices vertically, from top to bottom. All matrices need to have
+    /// the same number of columns and at least one matrix is required.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2};
+    /// let mat_b = matrix!{3, 4; 5, 6};
+    /// assert_eq!(Matrix::vconcat(&[&mat_a, &mat_b])?, matrix!{1, 2; 3, 4; 5, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn vconcat(mats: &[&Matrix<T>]) -> Result<Matrix<T>, DimensionError> {
+        let first = mats.first().ok_or(DimensionError::InvalidDimensions)?;
+        if let Some(mat) = mats.iter().find(|mat| mat.cols() != first.cols()) {
+            return Err(DimensionError::NoMatch(
+                first.dims,
+                mat.dims,
+                "stack vertically".to_owned(),
+            ));
+        }
+        let rows = mats.iter().map(|mat| mat.rows()).sum();
+        let mut entries = Vec::with_capacity(rows * first.cols());
+        for mat in mats {
+            entries.extend_from_slice(&mat.matrix);
+        }
+        Ok(Matrix {
+            dims: Dimensions::new(rows, first.cols()),
+            matrix: entries,
+        })
+    }
+
+    /// Concatenate two matrices vertically, placing `other` below `self`. Both matrices need to have
+    /// the same number of columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4};
+    /// let mat_b = matrix!{5, 6};
+    /// assert_eq!(mat_a.vstack(&mat_b)?, matrix!{1, 2; 3, 4; 5, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn vstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, DimensionError> {
+        Matrix::vconcat(&[self, other])
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Create a new matrix from a vec.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - Row count of matrix
+    /// * `cols` - Column count of matrix
+    /// * `vec` - Vector of length `rows x cols` where `vec[i * cols + j]` is the entry in row `i` and column `j`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat = matrix!{1, 2, 3; 3, 2, 1; 2, 1, 3};
+    /// println!("{}", mat);
+    ///
+    /// // Output:
+    /// // 1 2 3
+    /// // 3 2 1
+    /// // 2 1 3
+    /// ```
+    pub fn from_vec(rows: usize, cols: usize, vec: Vec<T>) -> Result<Matrix<T>, DimensionError> {
+        if vec.len() != rows * cols {
+            Err(DimensionError::InvalidInputDimensions(
+                vec.len(),
+                rows * cols,
+            ))
+        } else {
+            Ok(Matrix::<T> {
+                dims: Dimensions::new(rows, cols),
+                matrix: vec,
+            })
+        }
+    }
+
+    /// Create a new matrix from row-major `data`. This is [`Matrix::from_vec`] with the data
+    /// first, for callers that think of the buffer as the primary input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = Matrix::from_flat(vec![1, 2, 3, 4, 5, 6], 2, 3)?;
+    /// assert_eq!(mat, matrix!{1, 2, 3; 4, 5, 6});
+    /// assert_eq!(mat.into_flat(), vec![1, 2, 3, 4, 5, 6]);
+    /// # Ok(()) }
+    /// ```
+    pub fn from_flat(data: Vec<T>, rows: usize, cols: usize) -> Result<Matrix<T>, DimensionError> {
+        Matrix::from_vec(rows, cols, data)
+    }
+
+    /// Create a new matrix whose entry in row `i` and column `j` is `f(i, j)`.
+    ///
+    /// `f` is called exactly once per entry, in row-major order. Zero rows or columns result in
+    /// [`DimensionError::InvalidDimensions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - Row count of matrix
+    /// * `cols` - Column count of matrix
+    /// * `f` - Generator for the entries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let table = Matrix::from_fn(3, 3, |i, j| (i + 1) * (j + 1))?;
+    /// assert_eq!(table, matrix!{1, 2, 3; 2, 4, 6; 3, 6, 9});
+    /// # Ok(()) }
+    /// ```
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Result<Matrix<T>, DimensionError>
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        if rows == 0 || cols == 0 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        let mut matrix = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                matrix.push(f(i, j));
+            }
+        }
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(rows, cols),
+            matrix,
+        })
+    }
+
+    /// Create a new matrix from a slice of rows.
+    ///
+    /// An empty slice or empty rows result in [`DimensionError::InvalidDimensions`]. Rows that
+    /// differ in length from the first row result in [`DimensionError::Ragged`] with the index
+    /// of the offending row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// assert_eq!(Matrix::from_rows(&rows)?, matrix!{1, 2, 3; 4, 5, 6});
+    /// assert_eq!(
+    ///     Matrix::from_rows(&[vec![1, 2], vec![3]]),
+    ///     Err(DimensionError::Ragged(1, 1, 2))
+    /// );
+    /// # Ok(()) }
+    /// ```
+    pub fn from_rows(rows: &[Vec<T>]) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Clone,
+    {
+        let cols = check_lines(rows)?;
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(rows.len(), cols),
+            matrix: rows.iter().flatten().cloned().collect(),
+        })
+    }
+
+    /// Create a new matrix from a slice of columns.
+    ///
+    /// Errors are the same as for [`Matrix::from_rows`], with the index of the offending column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let cols = vec![vec![1, 4], vec![2, 5], vec![3, 6]];
+    /// assert_eq!(Matrix::from_cols(&cols)?, matrix!{1, 2, 3; 4, 5, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn from_cols(cols: &[Vec<T>]) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Clone,
+    {
+        let rows = check_lines(cols)?;
+        let mut matrix = Vec::with_capacity(rows * cols.len());
+        for i in 0..rows {
+            matrix.extend(cols.iter().map(|col| col[i].clone()));
+        }
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(rows, cols.len()),
+            matrix,
+        })
+    }
+
+    /// Remove row `at` from the matrix and return its entries.
+    ///
+    /// Indices that are out of bounds result in [`DimensionError::OutOfBounds`], removing the only
+    /// row of a matrix in [`DimensionError::InvalidDimensions`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat = matrix!{1, 2; 3, 4; 5, 6};
+    /// assert_eq!(mat.remove_row(1)?, vec![3, 4]);
+    /// assert_eq!(mat, matrix!{1, 2; 5, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn remove_row(&mut self, at: usize) -> Result<Vec<T>, DimensionError> {
+        if at >= self.rows() {
+            return Err(DimensionError::OutOfBounds(at, self.rows()));
+        }
+        if self.rows() == 1 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        let cols = self.cols();
+        let row = self.matrix.drain(at * cols..(at + 1) * cols).collect();
+        self.dims = Dimensions::new(self.rows() - 1, cols);
+        Ok(row)
+    }
+
+    /// Remove column `at` from the matrix and return its entries.
+    ///
+    /// Indices that are out of bounds result in [`DimensionError::OutOfBounds`], removing the only
+    /// column of a matrix in [`DimensionError::InvalidDimensions`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat.remove_col(0)?, vec![1, 4]);
+    /// assert_eq!(mat, matrix!{2, 3; 5, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn remove_col(&mut self, at: usize) -> Result<Vec<T>, DimensionError> {
+        if at >= self.cols() {
+            return Err(DimensionError::OutOfBounds(at, self.cols()));
+        }
+        if self.cols() == 1 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        let cols = self.cols();
+        let mut col = Vec::with_capacity(self.rows());
+        let mut entries = Vec::with_capacity(self.matrix.len() - self.rows());
+        for (idx, x) in std::mem::take(&mut self.matrix).into_iter().enumerate() {
+            if idx % cols == at {
+                col.push(x);
+            } else {
+                entries.push(x);
+            }
+        }
+        self.matrix = entries;
+        self.dims = Dimensions::new(self.rows(), cols - 1);
+        Ok(col)
+    }
+
+    /// Swap the rows `a` and `b`. Returns [`DimensionError::OutOfBounds`] if either row does not
+    /// exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat = matrix!{1, 2; 3, 4; 5, 6};
+    /// mat.swap_rows(0, 2)?;
+    /// assert_eq!(mat, matrix!{5, 6; 3, 4; 1, 2});
+    /// # Ok(()) }
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<(), DimensionError> {
+        let rows = self.rows();
+        if let Some(&idx) = [a, b].iter().find(|idx| **idx >= rows) {
+            return Err(DimensionError::OutOfBounds(idx, rows));
+        }
+        if a != b {
+            let cols = self.cols();
+            let (low, high) = (a.min(b), a.max(b));
+            let (head, tail) = self.matrix.split_at_mut(high * cols);
+            head[low * cols..(low + 1) * cols].swap_with_slice(&mut tail[..cols]);
+        }
+        Ok(())
+    }
+
+    /// Swap the columns `a` and `b`. Returns [`DimensionError::OutOfBounds`] if either column does
+    /// not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat = matrix!{1, 2, 3; 4, 5, 6};
+    /// mat.swap_cols(0, 1)?;
+    /// assert_eq!(mat, matrix!{2, 1, 3; 5, 4, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn swap_cols(&mut self, a: usize, b: usize) -> Result<(), DimensionError> {
+        let cols = self.cols();
+        if let Some(&idx) = [a, b].iter().find(|idx| **idx >= cols) {
+            return Err(DimensionError::OutOfBounds(idx, cols));
+        }
+        for i in 0..self.rows() {
+            self.matrix.swap(i * cols + a, i * cols + b);
+        }
+        Ok(())
+    }
+
+    /// Multiply every entry of row `row` with `factor`. Returns [`DimensionError::OutOfBounds`]
+    /// if the row does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat = matrix!{1, 2; 3, 4};
+    /// mat.scale_row(1, -2)?;
+    /// assert_eq!(mat, matrix!{1, 2; -6, -8});
+    /// # Ok(()) }
+    /// ```
+    pub fn scale_row(&mut self, row: usize, factor: T) -> Result<(), DimensionError>
+    where
+        T: MulAssign + Clone,
+    {
+        if row >= self.rows() {
+            return Err(DimensionError::OutOfBounds(row, self.rows()));
+        }
+        self[row].iter_mut().for_each(|x| *x *= factor.clone());
+        Ok(())
+    }
+
+    /// Add `factor` times row `source` to row `target`. Returns [`DimensionError::OutOfBounds`] if
+    /// either row does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat = matrix!{1, 2; 3, 4};
+    /// // eliminate the first entry of the second row
+    /// mat.add_scaled_row(1, 0, -3)?;
+    /// assert_eq!(mat, matrix!{1, 2; 0, -2});
+    /// # Ok(()) }
+    /// ```
+    pub fn add_scaled_row(
+        &mut self,
+        target: usize,
+        source: usize,
+        factor: T,
+    ) -> Result<(), DimensionError>
+    where
+        T: Mul<Output = T> + AddAssign + Clone,
+    {
+        let rows = self.rows();
+        if let Some(&idx) = [target, source].iter().find(|idx| **idx >= rows) {
+            return Err(DimensionError::OutOfBounds(idx, rows));
+        }
+        let source_row = self[source].to_vec();
+        self[target]
+            .iter_mut()
+            .zip(source_row)
+            .for_each(|(x, y)| *x += factor.clone() * y);
+        Ok(())
+    }
+
+    /// Consume the matrix and reinterpret its entries as a `rows x cols` matrix, keeping the
+    /// row-major order of the entries. The storage is reused without copying.
+    ///
+    /// `rows * cols` needs to be the number of entries, otherwise
+    /// [`DimensionError::InvalidInputDimensions`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 2, 3; 4, 5, 6};
+    /// let flat = mat.into_reshaped(1, 6)?;
+    /// assert_eq!(flat, matrix!{1, 2, 3, 4, 5, 6});
+    /// assert_eq!(flat.into_reshaped(3, 2)?, matrix!{1, 2; 3, 4; 5, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn into_reshaped(self, rows: usize, cols: usize) -> Result<Matrix<T>, DimensionError> {
+        match rows.checked_mul(cols) {
+            Some(len) if len == self.matrix.len() => Matrix::from_vec(rows, cols, self.matrix),
+            len => Err(DimensionError::InvalidInputDimensions(
+                self.matrix.len(),
+                len.unwrap_or(usize::MAX),
+            )),
+        }
+    }
+
+    /// Consume the matrix and return its entries in row-major order.
+    pub fn into_flat(self) -> Vec<T> {
+        self.matrix
+    }
+
+    /// Get a pointer to the entries, which are stored in row-major order.
+    pub fn as_ptr(&self) -> *const T {
+        self.matrix.as_ptr()
+    }
+
+    /// Transpose a square matrix in place, without allocating a new matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat_a = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9};
+    /// mat_a.transpose_in_place()?;
+    /// assert_eq!(mat_a, matrix!{1, 4, 7; 2, 5, 8; 3, 6, 9});
+    ///
+    /// let mut mat_b = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat_b.transpose_in_place(), Err(DimensionError::NoSquare));
+    /// # Ok(()) }
+    /// ```
+    pub fn transpose_in_place(&mut self) -> Result<(), DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        let dim = self.rows();
+        for i in 0..dim {
+            for j in (i + 1)..dim {
+                self.matrix.swap(i * dim + j, j * dim + i);
+            }
+        }
+        Ok(())
+    }
+
+    /// Borrow the block spanned by the row range `rows` and the column range `cols` without copying
+    /// it. The ranges are handled like in [`Matrix::submatrix`].
+    pub fn view<R, C>(&self, rows: R, cols: C) -> Result<MatrixView<'_, T>, DimensionError>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (row_start, row_end) = resolve_range(&rows, self.rows())?;
+        let (col_start, col_end) = resolve_range(&cols, self.cols())?;
+        let stride = self.cols();
+        Ok(MatrixView {
+            dims: Dimensions::new(row_end - row_start, col_end - col_start),
+            stride,
+            data: &self.matrix[row_start * stride + col_start..(row_end - 1) * stride + col_end],
+        })
+    }
+
+    /// Mutably borrow the block spanned by the row range `rows` and the column range `cols`
+    /// without copying it. The ranges are handled like in [`Matrix::submatrix`].
+    pub fn view_mut<R, C>(
+        &mut self,
+        rows: R,
+        cols: C,
+    ) -> Result<MatrixViewMut<'_, T>, DimensionError>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (row_start, row_end) = resolve_range(&rows, self.rows())?;
+        let (col_start, col_end) = resolve_range(&cols, self.cols())?;
+        let stride = self.cols();
+        Ok(MatrixViewMut {
+            dims: Dimensions::new(row_end - row_start, col_end - col_start),
+            stride,
+            data: &mut self.matrix
+                [row_start * stride + col_start..(row_end - 1) * stride + col_end],
+        })
+    }
+
+    /// Iterate over the entries of the matrix in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1.0, 2.0; 3.0, 4.0};
+    /// assert_eq!(mat.iter().copied().sum::<f64>(), 10.0);
+    /// assert_eq!(mat.iter().position(|x| *x == 3.0), Some(2));
+    /// # Ok(()) }
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.matrix.iter()
+    }
+
+    /// Iterate mutably over the entries of the matrix in row-major order.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.matrix.iter_mut()
+    }
+
+    /// Iterate over the entries of the matrix in row-major order together with their position
+    /// `(row, col)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{0, 2; 0, 0; 5, 0};
+    /// let non_zero: Vec<_> = mat.indexed_iter().filter(|(_, x)| **x != 0).collect();
+    /// assert_eq!(non_zero, vec![((0, 1), &2), ((2, 0), &5)]);
+    /// # Ok(()) }
+    /// ```
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let cols = self.cols();
+        self.matrix
+            .iter()
+            .enumerate()
+            .map(move |(idx, x)| ((idx / cols, idx % cols), x))
+    }
+
+    /// Iterate over the rows of the matrix as slices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1.0, 2.0; 3.0, 4.0};
+    /// let sums: Vec<f64> = mat.row_iter().map(|r| r.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3.0, 7.0]);
+    /// # Ok(()) }
+    /// ```
+    pub fn row_iter(&self) -> impl Iterator<Item = &[T]> {
+        self.matrix.chunks(self.cols())
+    }
+
+    /// Iterate over the rows of the matrix as mutable slices.
+    pub fn row_iter_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let cols = self.cols();
+        self.matrix.chunks_mut(cols)
+    }
+
+    /// Iterate over the columns of the matrix. Since the entries are stored row by row, every column
+    /// is collected into a vec of references.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 2; 3, 4};
+    /// let mut cols = mat.col_iter();
+    /// assert_eq!(cols.next(), Some(vec![&1, &3]));
+    /// assert_eq!(cols.next(), Some(vec![&2, &4]));
+    /// assert_eq!(cols.next(), None);
+    /// # Ok(()) }
+    /// ```
+    pub fn col_iter(&self) -> impl Iterator<Item = Vec<&T>> {
+        let (rows, cols) = (self.rows(), self.cols());
+        (0..cols).map(move |j| (0..rows).map(|i| &self.matrix[i * cols + j]).collect())
+    }
+
+    /// Fold every row of the matrix, starting from `init`. Returns one result per row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat.fold_rows(0, |sum, x| sum + x), vec![6, 15]);
+    /// # Ok(()) }
+    /// ```
+    pub fn fold_rows<U, F>(&self, init: U, mut f: F) -> Vec<U>
+    where
+        U: Clone,
+        F: FnMut(U, &T) -> U,
+    {
+        self.row_iter()
+            .map(|row| row.iter().fold(init.clone(), &mut f))
+            .collect()
+    }
+
+    /// Fold every column of the matrix, starting from `init`. Returns one result per column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 8, 3; 4, 5, 6};
+    /// assert_eq!(mat.fold_cols(i32::MIN, |max, x| max.max(*x)), vec![4, 8, 6]);
+    /// # Ok(()) }
+    /// ```
+    pub fn fold_cols<U, F>(&self, init: U, mut f: F) -> Vec<U>
+    where
+        U: Clone,
+        F: FnMut(U, &T) -> U,
+    {
+        let mut results = vec![init; self.cols()];
+        for row in self.row_iter() {
+            for (acc, x) in results.iter_mut().zip(row.iter()) {
+                *acc = f(acc.clone(), x);
+            }
+        }
+        results
+    }
+
+    /// Sum of the entries of every row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat.row_sum(), vec![6, 15]);
+    /// assert_eq!(mat.col_sum(), vec![5, 7, 9]);
+    /// # Ok(()) }
+    /// ```
+    pub fn row_sum(&self) -> Vec<T>
+    where
+        T: Clone + Zero,
+    {
+        self.fold_rows(T::zero(), |sum, x| sum + x.clone())
+    }
+
+    /// Sum of the entries of every column.
+    pub fn col_sum(&self) -> Vec<T>
+    where
+        T: Clone + Zero,
+    {
+        self.fold_cols(T::zero(), |sum, x| sum + x.clone())
+    }
+
+    /// Largest entry of every row. Entries that are not comparable, like `NaN`, are only returned if
+    /// they are the first entry of the row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 8, 3; 4, 5, 6};
+    /// assert_eq!(mat.row_max(), vec![8, 6]);
+    /// assert_eq!(mat.col_max(), vec![4, 8, 6]);
+    /// assert_eq!(mat.row_min(), vec![1, 4]);
+    /// assert_eq!(mat.col_min(), vec![1, 5, 3]);
+    /// # Ok(()) }
+    /// ```
+    pub fn row_max(&self) -> Vec<T>
+    where
+        T: Clone + PartialOrd,
+    {
+        self.reduce_rows(|max, x| x > max)
+    }
+
+    /// Largest entry of every column. Entries that are not comparable, like `NaN`, are only
+    /// returned if they are the first entry of the column.
+    pub fn col_max(&self) -> Vec<T>
+    where
+        T: Clone + PartialOrd,
+    {
+        self.reduce_cols(|max, x| x > max)
+    }
+
+    /// Smallest entry of every row. Entries that are not comparable, like `NaN`, are only returned
+    /// if they are the first entry of the row.
+    pub fn row_min(&self) -> Vec<T>
+    where
+        T: Clone + PartialOrd,
+    {
+        self.reduce_rows(|min, x| x < min)
+    }
+
+    /// Smallest entry of every column. Entries that are not comparable, like `NaN`, are only
+    /// returned if they are the first entry of the column.
+    pub fn col_min(&self) -> Vec<T>
+    where
+        T: Clone + PartialOrd,
+    {
+        self.reduce_cols(|min, x| x < min)
+    }
+
+    /// Pick one entry per row, replacing the current pick by `x` whenever `replace(pick, x)`.
+    fn reduce_rows<F>(&self, replace: F) -> Vec<T>
+    where
+        T: Clone,
+        F: Fn(&T, &T) -> bool,
+    {
+        self.row_iter()
+            .map(|row| {
+                row[1..]
+                    .iter()
+                    .fold(&row[0], |pick, x| if replace(pick, x) { x } else { pick })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Pick one entry per column, replacing the current pick by `x` whenever `replace(pick, x)`.
+    fn reduce_cols<F>(&self, replace: F) -> Vec<T>
+    where
+        T: Clone,
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut rows = self.row_iter();
+        let mut picks: Vec<&T> = rows.next().unwrap().iter().collect();
+        for row in rows {
+            for (pick, x) in picks.iter_mut().zip(row.iter()) {
+                if replace(pick, x) {
+                    *pick = x;
+                }
+            }
+        }
+        picks.into_iter().cloned().collect()
+    }
+
+    /// Consume the matrix and iterate over its rows.
+    pub fn into_rows(self) -> impl Iterator<Item = Vec<T>> {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut entries = self.matrix.into_iter();
+        (0..rows).map(move |_| entries.by_ref().take(cols).collect())
+    }
+
+    /// Consume the matrix and iterate over its columns.
+    pub fn into_cols(self) -> impl Iterator<Item = Vec<T>> {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut columns: Vec<Vec<T>> = (0..cols).map(|_| Vec::with_capacity(rows)).collect();
+        for (idx, x) in self.matrix.into_iter().enumerate() {
+            columns[idx % cols].push(x);
+        }
+        columns.into_iter()
+    }
+
+    /// Apply `f` to every entry, returning a matrix with the same dimensions and possibly a
+    /// different entry type. `f` is called on the entries in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, -2; -3, 4};
+    /// assert_eq!(mat.map(|x: &i64| x.abs()), matrix!{1, 2; 3, 4});
+    /// assert_eq!(mat.map(|x| *x as f64 / 2.0), matrix!{0.5, -1.0; -1.5, 2.0});
+    /// # Ok(()) }
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Matrix<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Matrix {
+            dims: self.dims,
+            matrix: self.matrix.iter().map(f).collect(),
+        }
+    }
+
+    /// Consume the matrix and apply `f` to every entry, returning a matrix with the same dimensions.
+    /// `f` is called on the entries in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat: Matrix<f64> = matrix!{-1.0, 0.5; 2.0, -0.25};
+    /// let relu = mat.map_into(|x| x.max(0.0));
+    /// assert_eq!(relu, matrix!{0.0, 0.5; 2.0, 0.0});
+    /// # Ok(()) }
+    /// ```
+    pub fn map_into<U, F>(self, f: F) -> Matrix<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Matrix {
+            dims: self.dims,
+            matrix: self.matrix.into_iter().map(f).collect(),
+        }
+    }
+
+    /// Apply `f` to every entry in place. `f` is called on the entries in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat = matrix!{1, -2; 5, 4};
+    /// mat.map_mut(|x| *x = (*x).clamp(0, 3));
+    /// assert_eq!(mat, matrix!{1, 0; 3, 3});
+    /// # Ok(()) }
+    /// ```
+    pub fn map_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.matrix.iter_mut().for_each(f);
+    }
+
+    /// Combine two matrices with the same dimensions entry by entry, returning a matrix of the
+    /// results of `f`. `f` is called on the pairs of entries in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 5; 3, 2};
+    /// let mat_b = matrix!{4, 2; 3, 6};
+    /// assert_eq!(mat_a.zip_with(&mat_b, |a, b| *a.max(b))?, matrix!{4, 5; 3, 6});
+    /// # Ok(()) }
+    /// ```
+    pub fn zip_with<U, V, F>(&self, other: &Matrix<U>, f: F) -> Result<Matrix<V>, DimensionError>
+    where
+        F: FnMut(&T, &U) -> V,
+    {
+        self.zip_with_op(other, "combine", f)
+    }
+
+    /// Like [`Matrix::zip_with`], with `op` describing the operation in the dimension error.
+    pub(crate) fn zip_with_op<U, V, F>(
+        &self,
+        other: &Matrix<U>,
+        op: &str,
+        mut f: F,
+    ) -> Result<Matrix<V>, DimensionError>
+    where
+        F: FnMut(&T, &U) -> V,
+    {
+        if self.dims != other.dims {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                other.dims,
+                op.to_owned(),
+            ));
+        }
+        Ok(Matrix {
+            dims: self.dims,
+            matrix: self
+                .matrix
+                .iter()
+                .zip(other.matrix.iter())
+                .map(|(a, b)| f(a, b))
+                .collect(),
+        })
+    }
+}
+
+impl<T> From<Vector<T>> for Matrix<T>
+where
+    T: Zero + One + Clone,
+{
+    fn from(v: Vector<T>) -> Matrix<T> {
+        if v.is_row_vector() {
+            Matrix::<T>::from_vec(1, v.size(), v.entries).unwrap()
+        } else {
+            Matrix::<T>::from_vec(v.size(), 1, v.entries).unwrap()
+        }
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = DimensionError;
+
+    /// Create a matrix from a vec of rows. Errors are the same as for [`Matrix::from_rows`].
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Matrix<T>, DimensionError> {
+        let cols = check_lines(&rows)?;
+        Ok(Matrix::<T> {
+            dims: Dimensions::new(rows.len(), cols),
+            matrix: rows.into_iter().flatten().collect(),
+        })
+    }
+}
+
+/// Split the matrix into its rows.
+impl<T> From<Matrix<T>> for Vec<Vec<T>> {
+    fn from(mat: Matrix<T>) -> Vec<Vec<T>> {
+        mat.into_rows().collect()
+    }
+}
+
+// GETTERS
+impl<T> Matrix<T> {
+    /// Get the number of rows
+    pub fn rows(&self) -> usize {
+        self.dims.rows()
+    }
+
+    /// Get the number of columns
+    pub fn cols(&self) -> usize {
+        self.dims.cols()
+    }
+
+    /// Returns true if the matrix is a square matrix, false otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<i32> = Matrix::one(3)?;
+    /// let mat_b: Matrix<f32> = Matrix::zero(3, 4)?;
+    /// assert_eq!(mat_a.is_square(), true);
+    /// assert_eq!(mat_b.is_square(), false);
+    /// # Ok(()) }
+    /// ```
+    pub fn is_square(&self) -> bool {
+        self.dims.is_square()
+    }
+
+    pub fn dims(&self) -> Dimensions {
+        Dimensions::new(self.rows(), self.cols())
+    }
+
+    pub fn entry(&self, i: impl Into<usize>, j: impl Into<usize>) -> T
+    where
+        T: Clone,
+    {
+        self.matrix[self.cols() * i.into() + j.into()].clone()
+    }
+
+    pub fn entry_mut(&mut self, i: impl Into<usize>, j: impl Into<usize>) -> &mut T {
+        let cols = self.cols();
         &mut self.matrix[cols * i.into() + j.into()]
     }
+
+    /// Get a reference to the entry in row `row` and column `col`, or `None` if the position is out
+    /// of bounds. Unlike indexing with `mat[(row, col)]` or `mat[row][col]`, this never panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat.get(1, 2), Some(&6));
+    /// assert_eq!(mat.get(0, 3), None);
+    /// assert_eq!(mat.get(2, 0), None);
+    /// # Ok(()) }
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows() && col < self.cols() {
+            self.matrix.get(row * self.cols() + col)
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the entry in row `row` and column `col`, or `None` if the
+    /// position is out of bounds.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.rows() && col < self.cols() {
+            let cols = self.cols();
+            self.matrix.get_mut(row * cols + col)
+        } else {
+            None
+        }
+    }
+}
+
+/// Resolve `range` into `start..end` for a dimension of size `len`.
+pub(crate) fn resolve_range<R: RangeBounds<usize>>(
+    range: &R,
+    len: usize,
+) -> Result<(usize, usize), DimensionError> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end + 1,
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => len,
+    };
+    if end > len {
+        return Err(DimensionError::OutOfBounds(end - 1, len));
+    }
+    if start >= end {
+        return Err(DimensionError::InvalidDimensions);
+    }
+    Ok((start, end))
+}
+
+/// Check that `lines` is non-empty and all its entries have the same, non-zero length and return
+/// that length.
+fn check_lines<T>(lines: &[Vec<T>]) -> Result<usize, DimensionError> {
+    let len = match lines.first() {
+        Some(first) if !first.is_empty() => first.len(),
+        _ => return Err(DimensionError::InvalidDimensions),
+    };
+    match lines.iter().position(|line| line.len() != len) {
+        Some(idx) => Err(DimensionError::Ragged(idx, lines[idx].len(), len)),
+        None => Ok(len),
+    }
+}
+
+/// Convert `n` to a float, which cannot fail for the float types.
+fn float<T: Float>(n: usize) -> T {
+    T::from(n).unwrap()
+}
+
+/// The binomial coefficient `n` choose `k` as a float. Every partial product is a binomial
+/// coefficient itself, so the result is exact as long as it can be represented by `T`.
+fn binomial<T: Float>(n: usize, k: usize) -> T {
+    (1..=k).fold(T::one(), |c, m| c * float(n - k + m) / float(m))
 }
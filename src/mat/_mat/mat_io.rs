@@ -0,0 +1,405 @@
+use crate::err::{DimensionError, ParseMatrixError};
+use crate::mat::{LatexEnv, Matrix};
+#[cfg(feature = "io")]
+use crate::{err::MatrixIoError, mat::CsvOptions};
+#[cfg(feature = "io")]
+use num_traits::Zero;
+use std::convert::TryFrom;
+use std::fmt::Display;
+#[cfg(feature = "io")]
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+#[cfg(feature = "io")]
+impl CsvOptions {
+    /// Create the default options, which separate values by commas.
+    pub fn new() -> CsvOptions {
+        CsvOptions { delimiter: ',' }
+    }
+
+    /// Separate values by `delimiter`, e.g. `'\t'` or `';'`.
+    pub fn delimiter(mut self, delimiter: char) -> CsvOptions {
+        self.delimiter = delimiter;
+        self
+    }
+}
+
+#[cfg(feature = "io")]
+impl Default for CsvOptions {
+    fn default() -> CsvOptions {
+        CsvOptions::new()
+    }
+}
+
+#[cfg(feature = "io")]
+impl<T> Matrix<T> {
+    /// Read a matrix from comma-separated values, one row per line. Whitespace around values and
+    /// trailing newlines are ignored.
+    ///
+    /// Rows of differing lengths result in [`DimensionError::Ragged`] with the line of the
+    /// offending row, counted from one. Entries that cannot be parsed result in
+    /// [`ParseMatrixError::InvalidEntry`] with their (zero-based) position in the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::MatrixIoError;
+    /// # fn main() -> Result<(), MatrixIoError> {
+    /// let csv = "1.5, -2\n3e2, 4\n";
+    /// let mat: Matrix<f64> = Matrix::from_csv(csv.as_bytes())?;
+    /// assert_eq!(mat, matrix!{1.5, -2.0; 300.0, 4.0});
+    /// # Ok(()) }
+    /// ```
+    pub fn from_csv<R>(reader: R) -> Result<Matrix<T>, MatrixIoError>
+    where
+        R: Read,
+        T: FromStr,
+    {
+        Self::from_csv_with(reader, &CsvOptions::new())
+    }
+
+    /// Read a matrix from delimiter-separated values as described by `options`. See
+    /// [`Matrix::from_csv`].
+    pub fn from_csv_with<R>(mut reader: R, options: &CsvOptions) -> Result<Matrix<T>, MatrixIoError>
+    where
+        R: Read,
+        T: FromStr,
+    {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        Ok(parse_rows(&s, Some(options.delimiter))?)
+    }
+
+    /// Write the matrix as comma-separated values, one row per line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mut csv = Vec::new();
+    /// matrix!{1, 2; 3, 4}.to_csv(&mut csv).unwrap();
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "1,2\n3,4\n");
+    /// ```
+    pub fn to_csv<W>(&self, writer: W) -> std::io::Result<()>
+    where
+        W: Write,
+        T: Display,
+    {
+        self.to_csv_with(writer, &CsvOptions::new())
+    }
+
+    /// Write the matrix as delimiter-separated values as described by `options`. See
+    /// [`Matrix::to_csv`].
+    pub fn to_csv_with<W>(&self, mut writer: W, options: &CsvOptions) -> std::io::Result<()>
+    where
+        W: Write,
+        T: Display,
+    {
+        for row in self.matrix.chunks(self.cols()) {
+            for (j, entry) in row.iter().enumerate() {
+                if j > 0 {
+                    write!(writer, "{}", options.delimiter)?;
+                }
+                write!(writer, "{entry}")?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "io")]
+impl<T> Matrix<T>
+where
+    T: Clone + Zero,
+{
+    /// Read a matrix in the [Matrix Market](https://math.nist.gov/MatrixMarket/formats.html)
+    /// exchange format.
+    ///
+    /// Both the dense `array` and the sparse `coordinate` format are supported, where entries
+    /// missing from a `coordinate` file are zero. The field has to be `real` or `integer` and the
+    /// symmetry `general` or `symmetric`; symmetric storage is expanded to the full matrix.
+    ///
+    /// Malformed lines result in [`ParseMatrixError::InvalidLine`] with the line counted from one,
+    /// entries that cannot be parsed in [`ParseMatrixError::InvalidEntry`] with their position in
+    /// the matrix. A number of entries that differs from the size line results in
+    /// [`DimensionError::InvalidInputDimensions`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::MatrixIoError;
+    /// # fn main() -> Result<(), MatrixIoError> {
+    /// let mtx = "%%MatrixMarket matrix coordinate real symmetric
+    /// % a comment
+    /// 3 3 3
+    /// 1 1 2.0
+    /// 3 1 -1.5
+    /// 2 2 4.0
+    /// ";
+    /// let mat: Matrix<f64> = Matrix::from_matrix_market(mtx.as_bytes())?;
+    /// assert_eq!(mat, matrix!{2.0, 0.0, -1.5; 0.0, 4.0, 0.0; -1.5, 0.0, 0.0});
+    /// # Ok(()) }
+    /// ```
+    pub fn from_matrix_market<R>(mut reader: R) -> Result<Matrix<T>, MatrixIoError>
+    where
+        R: Read,
+        T: FromStr,
+    {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        Ok(parse_matrix_market(&s)?)
+    }
+
+    /// Write the matrix in the Matrix Market `array` format with a `general` header. The field is
+    /// `integer` if every entry is written as an integer, as for the integer types, and `real`
+    /// otherwise. Entries are written in column-major order, as the format requires.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mut mtx = Vec::new();
+    /// matrix!{1, 2; 3, 4}.to_matrix_market(&mut mtx).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(mtx).unwrap(),
+    ///     "%%MatrixMarket matrix array integer general\n2 2\n1\n3\n2\n4\n"
+    /// );
+    ///
+    /// let mut mtx = Vec::new();
+    /// matrix!{0.5}.to_matrix_market(&mut mtx).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(mtx).unwrap(),
+    ///     "%%MatrixMarket matrix array real general\n1 1\n0.5\n"
+    /// );
+    /// ```
+    pub fn to_matrix_market<W>(&self, mut writer: W) -> std::io::Result<()>
+    where
+        W: Write,
+        T: Display,
+    {
+        let entries: Vec<String> = (0..self.cols())
+            .flat_map(|j| (0..self.rows()).map(move |i| self[i][j].to_string()))
+            .collect();
+        let integer = entries.iter().all(|entry| {
+            let digits = entry.strip_prefix('-').unwrap_or(entry);
+            !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+        });
+        let field = if integer { "integer" } else { "real" };
+        writeln!(writer, "%%MatrixMarket matrix array {field} general")?;
+        writeln!(writer, "{} {}", self.rows(), self.cols())?;
+        for entry in entries {
+            writeln!(writer, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a Matrix Market file, see [`Matrix::from_matrix_market`].
+#[cfg(feature = "io")]
+fn parse_matrix_market<T>(s: &str) -> Result<Matrix<T>, ParseMatrixError>
+where
+    T: Clone + Zero + FromStr,
+{
+    // lines are enumerated from zero, but reported from one
+    let invalid =
+        |(idx, content): (usize, &str)| ParseMatrixError::InvalidLine(idx + 1, content.to_owned());
+    let mut lines = s.lines().enumerate();
+
+    let banner = lines.next().unwrap_or((0, ""));
+    let header: Vec<String> = banner.1.split_whitespace().map(str::to_lowercase).collect();
+    let (coordinate, symmetric) = match header.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["%%matrixmarket", "matrix", format, "real" | "integer", symmetry]
+            if (format == "array" || format == "coordinate")
+                && (symmetry == "general" || symmetry == "symmetric") =>
+        {
+            (format == "coordinate", symmetry == "symmetric")
+        }
+        _ => return Err(invalid(banner)),
+    };
+
+    let mut lines = lines.filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('%'));
+    let size_line = lines.next().unwrap_or((0, ""));
+    let size: Vec<usize> = size_line
+        .1
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .map_err(|_| invalid(size_line))?;
+    let (rows, cols, nnz) = match size[..] {
+        [rows, cols] if !coordinate => (rows, cols, 0),
+        [rows, cols, nnz] if coordinate => (rows, cols, nnz),
+        _ => return Err(invalid(size_line)),
+    };
+    if symmetric && rows != cols {
+        return Err(DimensionError::NoSquare.into());
+    }
+    let mut mat = Matrix::from_fn(rows, cols, |_, _| T::zero())?;
+
+    let parse_entry = |token: &str, i: usize, j: usize| {
+        token
+            .parse::<T>()
+            .map_err(|_| ParseMatrixError::InvalidEntry(i, j, token.to_owned()))
+    };
+    if coordinate {
+        let mut count = 0;
+        for line in lines {
+            count += 1;
+            let (i, j, token) = match line.1.split_whitespace().collect::<Vec<_>>()[..] {
+                [i, j, token] => match (i.parse::<usize>(), j.parse::<usize>()) {
+                    (Ok(i), Ok(j)) if i > 0 && j > 0 => (i - 1, j - 1, token),
+                    _ => return Err(invalid(line)),
+                },
+                _ => return Err(invalid(line)),
+            };
+            if i >= rows {
+                return Err(DimensionError::OutOfBounds(i, rows).into());
+            }
+            if j >= cols {
+                return Err(DimensionError::OutOfBounds(j, cols).into());
+            }
+            let entry = parse_entry(token, i, j)?;
+            if symmetric {
+                mat[j][i] = entry.clone();
+            }
+            mat[i][j] = entry;
+        }
+        if count != nnz {
+            return Err(DimensionError::InvalidInputDimensions(count, nnz).into());
+        }
+    } else {
+        // entries are stored column by column, only the lower triangle for symmetric matrices
+        let positions = (0..cols)
+            .flat_map(|j| (if symmetric { j } else { 0 }..rows).map(move |i| (i, j)))
+            .collect::<Vec<_>>();
+        let tokens = lines
+            .flat_map(|(_, line)| line.split_whitespace())
+            .collect::<Vec<_>>();
+        if tokens.len() != positions.len() {
+            return Err(
+                DimensionError::InvalidInputDimensions(tokens.len(), positions.len()).into(),
+            );
+        }
+        for ((i, j), token) in positions.into_iter().zip(tokens) {
+            let entry = parse_entry(token, i, j)?;
+            if symmetric {
+                mat[j][i] = entry.clone();
+            }
+            mat[i][j] = entry;
+        }
+    }
+    Ok(mat)
+}
+
+impl<T> Matrix<T>
+where
+    T: Display,
+{
+    /// Write the matrix as a LaTeX matrix environment, with rows separated by `\\` and entries
+    /// by `&`. Entries are formatted with `precision` digits after the decimal point, if given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{LatexEnv, Matrix};
+    /// # use libmat::matrix;
+    /// let mat = matrix!{1.0, -0.5; 0.25, 2.0};
+    /// assert_eq!(
+    ///     mat.to_latex(LatexEnv::BMatrix, Some(2)),
+    ///     "\\begin{bmatrix}\n1.00 & -0.50 \\\\\n0.25 & 2.00\n\\end{bmatrix}"
+    /// );
+    /// ```
+    pub fn to_latex(&self, env: LatexEnv, precision: Option<usize>) -> String {
+        let env = match env {
+            LatexEnv::PMatrix => "pmatrix",
+            LatexEnv::BMatrix => "bmatrix",
+            LatexEnv::VMatrix => "vmatrix",
+        };
+        let rows = self
+            .format_entries(precision)
+            .map(|row| row.join(" & "))
+            .collect::<Vec<_>>()
+            .join(" \\\\\n");
+        format!("\\begin{{{env}}}\n{rows}\n\\end{{{env}}}")
+    }
+
+    /// Write the matrix as a Markdown pipe table with right-aligned columns and an empty header.
+    /// Entries are formatted with `precision` digits after the decimal point, if given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert_eq!(
+    ///     matrix!{1, 2; 3, 4}.to_markdown(None),
+    ///     "| | |\n|---:|---:|\n| 1 | 2 |\n| 3 | 4 |"
+    /// );
+    /// ```
+    pub fn to_markdown(&self, precision: Option<usize>) -> String {
+        let mut lines = vec![
+            format!("|{}", " |".repeat(self.cols())),
+            format!("|{}", "---:|".repeat(self.cols())),
+        ];
+        lines.extend(
+            self.format_entries(precision)
+                .map(|row| format!("| {} |", row.join(" | "))),
+        );
+        lines.join("\n")
+    }
+
+    /// Format the entries row by row, with `precision` digits after the decimal point if given.
+    fn format_entries(&self, precision: Option<usize>) -> impl Iterator<Item = Vec<String>> + '_ {
+        self.matrix.chunks(self.cols()).map(move |row| {
+            row.iter()
+                .map(|n| match precision {
+                    Some(precision) => format!("{n:.precision$}"),
+                    None => format!("{n}"),
+                })
+                .collect()
+        })
+    }
+}
+
+/// Parse `s` into a matrix with one row per line. Entries are separated by `delimiter`, or by
+/// whitespace if it is `None`.
+pub(super) fn parse_rows<T>(s: &str, delimiter: Option<char>) -> Result<Matrix<T>, ParseMatrixError>
+where
+    T: FromStr,
+{
+    let parse_line = |(i, line): (usize, &str)| {
+        let tokens: Vec<&str> = match delimiter {
+            _ if line.trim().is_empty() => Vec::new(),
+            Some(delimiter) => line.split(delimiter).map(str::trim).collect(),
+            None => line.split_whitespace().collect(),
+        };
+        tokens
+            .into_iter()
+            .enumerate()
+            .map(|(j, token)| {
+                token
+                    .parse::<T>()
+                    .map_err(|_| ParseMatrixError::InvalidEntry(i, j, token.to_owned()))
+            })
+            .collect::<Result<Vec<T>, ParseMatrixError>>()
+    };
+    let rows = s
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(parse_line)
+        .collect::<Result<Vec<Vec<T>>, ParseMatrixError>>()?;
+    // every line is a row, but lines are reported from one
+    Matrix::try_from(rows).map_err(|err| match err {
+        DimensionError::Ragged(idx, len, expected) => {
+            DimensionError::Ragged(idx + 1, len, expected).into()
+        }
+        err => err.into(),
+    })
+}
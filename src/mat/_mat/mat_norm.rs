@@ -0,0 +1,177 @@
+use crate::err::DimensionError;
+use crate::mat::{Matrix, PivotMagnitude};
+use num_traits::{Float, Signed, Zero};
+use std::iter::Sum;
+use std::ops::DivAssign;
+
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// Calculate the Frobenius norm, which is the square root of the sum of all squared entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a: Matrix<f64> = matrix!{1.0, -2.0; -2.0, 4.0};
+    /// assert_eq!(mat_a.frobenius_norm(), 5.0);
+    /// ```
+    #[doc(alias = "norm_frobenius")]
+    pub fn frobenius_norm(&self) -> T {
+        frobenius_norm(self.row_iter())
+    }
+
+    /// Calculate the 2-norm (spectral norm), which is the largest singular value. The result is
+    /// `NaN` if the singular value decomposition does not converge, as for non-finite entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a: Matrix<f64> = matrix!{3.0, 0.0; 4.0, 5.0};
+    /// assert!((mat_a.two_norm() - 45_f64.sqrt()).abs() < 1e-10);
+    /// ```
+    pub fn two_norm(&self) -> T {
+        self.svd().map_or_else(|_| T::nan(), |(_, s, _)| s[0])
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Signed + PartialOrd,
+{
+    /// Calculate the 1-norm, which is the maximum absolute column sum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a: Matrix<f64> = matrix!{1.0, -2.0; -3.0, 4.0};
+    /// assert_eq!(mat_a.one_norm(), 6.0);
+    /// ```
+    #[doc(alias = "norm_one")]
+    pub fn one_norm(&self) -> T {
+        one_norm(self.row_iter(), self.cols(), T::abs)
+    }
+
+    /// Calculate the infinity-norm, which is the maximum absolute row sum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a: Matrix<f64> = matrix!{1.0, -2.0; -3.0, 4.0};
+    /// assert_eq!(mat_a.inf_norm(), 7.0);
+    /// ```
+    #[doc(alias = "norm_inf")]
+    pub fn inf_norm(&self) -> T {
+        inf_norm(self.row_iter(), T::abs)
+    }
+
+    /// Calculate the max-norm, which is the maximum absolute value of all entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let mat_a = matrix!{1, -5; -3, 4};
+    /// assert_eq!(mat_a.max_norm(), 5);
+    /// ```
+    #[doc(alias = "norm_max")]
+    pub fn max_norm(&self) -> T {
+        max_norm(self.row_iter(), T::abs)
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Float + Signed + Sum + DivAssign + PivotMagnitude,
+{
+    /// Calculate the condition number `‖A‖₁ ‖A⁻¹‖₁` with respect to the 1-norm, which bounds how
+    /// much relative errors in `b` can be amplified when solving `A x = b`. Returns `None` if the
+    /// matrix is singular.
+    ///
+    /// The inverse is computed explicitly, so this costs about as much as [`Inv::inv`](num_traits::Inv::inv).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{1.0, 2.0; 3.0, 4.0};
+    /// assert!((mat_a.cond()?.unwrap() - 21.0).abs() < 1e-12);
+    /// assert_eq!(matrix!{1.0, 2.0; 2.0, 4.0}.cond()?, None);
+    /// # Ok(()) }
+    /// ```
+    pub fn cond(&self) -> Result<Option<T>, DimensionError> {
+        let inv = self.solve_mat(&Matrix::one(self.rows())?)?;
+        Ok(inv.map(|inv| self.one_norm() * inv.one_norm()))
+    }
+}
+
+// The norms are computed over the rows as slices, so that matrices and views share them. The
+// absolute value is passed in, since both `Signed` and `Float` types provide one.
+
+/// The maximum absolute column sum of `rows`, which have `cols` entries each.
+pub(crate) fn one_norm<'a, T, I>(rows: I, cols: usize, abs: fn(&T) -> T) -> T
+where
+    T: Zero + PartialOrd + 'a,
+    I: Iterator<Item = &'a [T]>,
+{
+    let mut sums: Vec<T> = (0..cols).map(|_| T::zero()).collect();
+    for row in rows {
+        for (sum, x) in sums.iter_mut().zip(row.iter()) {
+            *sum = std::mem::replace(sum, T::zero()) + abs(x);
+        }
+    }
+    sums.into_iter().fold(T::zero(), max)
+}
+
+/// The maximum absolute row sum of `rows`.
+pub(crate) fn inf_norm<'a, T, I>(rows: I, abs: fn(&T) -> T) -> T
+where
+    T: Zero + PartialOrd + 'a,
+    I: Iterator<Item = &'a [T]>,
+{
+    rows.map(|row| row.iter().fold(T::zero(), |sum, x| sum + abs(x)))
+        .fold(T::zero(), max)
+}
+
+/// The maximum absolute entry of `rows`.
+pub(crate) fn max_norm<'a, T, I>(rows: I, abs: fn(&T) -> T) -> T
+where
+    T: Zero + PartialOrd + 'a,
+    I: Iterator<Item = &'a [T]>,
+{
+    rows.flat_map(|row| row.iter())
+        .map(abs)
+        .fold(T::zero(), max)
+}
+
+/// The square root of the sum of all squared entries of `rows`.
+pub(crate) fn frobenius_norm<'a, T, I>(rows: I) -> T
+where
+    T: Float + 'a,
+    I: Iterator<Item = &'a [T]>,
+{
+    rows.flat_map(|row| row.iter())
+        .fold(T::zero(), |sum, x| sum + *x * *x)
+        .sqrt()
+}
+
+/// The larger of `a` and `b`, where `a` is kept if the two cannot be compared.
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
@@ -1,7 +1,7 @@
 // use crate::err::DimensionError;
 use crate::{
     err::DimensionError,
-    mat::{Matrix, Vector},
+    mat::{dims::Dimensions, Matrix, Vector},
 };
 use num_traits::identities::{One, Zero};
 use std::ops::{
@@ -9,6 +9,10 @@ use std::ops::{
 };
 use std::result::Result;
 
+/// Block size used by [`Matrix::checked_mul`] and the `Mul` implementations, chosen so that a
+/// block of rows of both operands fits into the cache for typical sizes.
+const MUL_BLOCK_SIZE: usize = 64;
+
 // impl Matrix<i64> {
 //     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 //     #[target_feature(enable = "avx2")]
@@ -89,6 +93,28 @@ where
     T: AddAssign + Clone,
 {
     fn add_assign(&mut self, rhs: Matrix<T>) {
+        *self += &rhs;
+    }
+}
+
+/// In-place elementwise addition of a borrowed matrix, without allocating. Panics if the
+/// dimensions do not match, use [`Matrix::checked_add`] for a fallible version.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// let mut mat_a = matrix!{1, 2; 3, 4};
+/// let mat_b = matrix!{1, 1; 1, 1};
+/// mat_a += &mat_b;
+/// assert_eq!(mat_a, matrix!{2, 3; 4, 5});
+/// ```
+impl<T> AddAssign<&Matrix<T>> for Matrix<T>
+where
+    T: AddAssign + Clone,
+{
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
         if self.dims != rhs.dims {
             panic!("Dimensions do not match.");
         }
@@ -157,7 +183,7 @@ where
             Err(DimensionError::NoMatch(
                 self.dims,
                 rhs.dims,
-                "add".to_owned(),
+                "subtract".to_owned(),
             ))
         } else {
             let mut result_matrix = self;
@@ -172,6 +198,28 @@ where
     T: SubAssign + Clone,
 {
     fn sub_assign(&mut self, rhs: Matrix<T>) {
+        *self -= &rhs;
+    }
+}
+
+/// In-place elementwise subtraction of a borrowed matrix, without allocating. Panics if the
+/// dimensions do not match, use [`Matrix::checked_sub`] for a fallible version.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// let mut mat_a = matrix!{1, 2; 3, 4};
+/// let mat_b = matrix!{1, 1; 1, 1};
+/// mat_a -= &mat_b;
+/// assert_eq!(mat_a, matrix!{0, 1; 2, 3});
+/// ```
+impl<T> SubAssign<&Matrix<T>> for Matrix<T>
+where
+    T: SubAssign + Clone,
+{
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
         if self.dims != rhs.dims {
             panic!("Dimensions do not match.");
         }
@@ -243,6 +291,82 @@ where
     }
 }
 
+/// Elementwise negation of a borrowed matrix, returning a new matrix.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// let mat_a: Matrix<i32> = matrix!{1, 2; 3, 4};
+/// assert_eq!((-&mat_a * &Matrix::one(2)?)?, matrix!{-1, -2; -3, -4});
+/// # Ok(()) }
+/// ```
+impl<T> Neg for &Matrix<T>
+where
+    T: Neg<Output = T> + Clone,
+{
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|x| -x.clone())
+    }
+}
+
+macro_rules! impl_ref_elementwise_ops {
+    ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident, $checked:ident, $name:literal) => {
+        #[doc = concat!("Elementwise ", $name, " of borrowed matrices, without consuming either operand.")]
+        impl<T> $op<&Matrix<T>> for &Matrix<T>
+        where
+            T: $assign_op + Clone,
+        {
+            type Output = Result<Matrix<T>, DimensionError>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Self::Output {
+                self.$checked(rhs)
+            }
+        }
+
+        #[doc = concat!("Elementwise ", $name, " of a borrowed matrix, reusing the buffer of the left one.")]
+        impl<T> $op<&Matrix<T>> for Matrix<T>
+        where
+            T: $assign_op + Clone,
+        {
+            type Output = Result<Matrix<T>, DimensionError>;
+
+            fn $method(self, rhs: &Matrix<T>) -> Self::Output {
+                if self.dims != rhs.dims {
+                    return Err(DimensionError::NoMatch(
+                        self.dims,
+                        rhs.dims,
+                        $name.to_owned(),
+                    ));
+                }
+                let mut result_matrix = self;
+                $assign_op::$assign_method(&mut result_matrix, rhs);
+                Ok(result_matrix)
+            }
+        }
+
+        #[doc = concat!("Elementwise ", $name, " of a matrix to a borrowed one.")]
+        impl<T> $op<Matrix<T>> for &Matrix<T>
+        where
+            T: $assign_op + Clone,
+        {
+            type Output = Result<Matrix<T>, DimensionError>;
+
+            fn $method(self, rhs: Matrix<T>) -> Self::Output {
+                self.$checked(&rhs)
+            }
+        }
+    };
+}
+
+impl_ref_elementwise_ops!(Add, add, AddAssign, add_assign, checked_add, "add");
+impl_ref_elementwise_ops!(Sub, sub, SubAssign, sub_assign, checked_sub, "subtract");
+
 /// Matrix multiplicaiton as described in
 /// [Matrix multipication](https://en.wikipedia.org/wiki/Matrix_multiplication),
 /// so the left matrix needs to have the same amount of columns as the right one has rows.
@@ -262,41 +386,365 @@ where
 /// ```
 impl<T> Mul for Matrix<T>
 where
-    T: Zero + One + Clone + std::iter::Sum,
+    T: Zero + One + Clone,
 {
     type Output = Result<Matrix<T>, DimensionError>;
 
     fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        self.checked_mul(&rhs)
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Add `rhs` without consuming either matrix. Mismatched dimensions result in
+    /// [`DimensionError::NoMatch`] with both dimensions, unlike `+=`, which panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4};
+    /// assert_eq!(mat_a.checked_add(&mat_a)?, matrix!{2, 4; 6, 8});
+    /// assert!(mat_a.checked_add(&Matrix::one(3)?).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn checked_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, DimensionError>
+    where
+        T: AddAssign + Clone,
+    {
+        self.zip_with_op(rhs, "add", |a, b| {
+            let mut sum = a.clone();
+            sum += b.clone();
+            sum
+        })
+    }
+
+    /// Subtract `rhs` without consuming either matrix. Mismatched dimensions result in
+    /// [`DimensionError::NoMatch`] with both dimensions, unlike `-=`, which panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4};
+    /// assert_eq!(mat_a.checked_sub(&Matrix::one(2)?)?, matrix!{0, 2; 3, 3});
+    /// # Ok(()) }
+    /// ```
+    pub fn checked_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, DimensionError>
+    where
+        T: SubAssign + Clone,
+    {
+        self.zip_with_op(rhs, "subtract", |a, b| {
+            let mut diff = a.clone();
+            diff -= b.clone();
+            diff
+        })
+    }
+
+    /// Multiply with `rhs` without consuming either matrix. If the column count of `self` does
+    /// not match the row count of `rhs`, this results in [`DimensionError::NoMatch`] with both
+    /// dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2, 3};
+    /// assert_eq!(mat_a.checked_mul(&mat_a.transpose())?, matrix!{14});
+    /// assert_eq!(
+    ///     mat_a.checked_mul(&mat_a).unwrap_err().to_string(),
+    ///     "Dimensions of two matrices do not match in the correct way. Cannot multiply 1x3 matrix with 1x3 matrix."
+    /// );
+    /// # Ok(()) }
+    /// ```
+    pub fn checked_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Zero + One + Clone,
+    {
+        self.checked_mul_blocked(rhs, MUL_BLOCK_SIZE)
+    }
+
+    /// Multiply with `rhs` like [`checked_mul`](Matrix::checked_mul), but with a custom block
+    /// size. The rows of `self` and the columns of `rhs` are processed in blocks of
+    /// `block_size`, so that they stay in the cache while they are reused.
+    ///
+    /// Every entry is summed up from left to right, starting with the first product, so the
+    /// result does not depend on the block size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = Matrix::from_fn(50, 40, |i, j| (i * j) as f64 / 7.0)?;
+    /// let mat_b: Matrix<f64> = Matrix::from_fn(40, 30, |i, j| (i + j) as f64 / 3.0)?;
+    /// assert_eq!(mat_a.checked_mul_blocked(&mat_b, 8)?, mat_a.checked_mul(&mat_b)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn checked_mul_blocked(
+        &self,
+        rhs: &Matrix<T>,
+        block_size: usize,
+    ) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Zero + One + Clone,
+    {
+        assert!(block_size > 0, "Block size cannot be zero.");
         if self.cols() != rhs.rows() {
-            Err(DimensionError::NoMatch(
+            return Err(DimensionError::NoMatch(
                 self.dims,
                 rhs.dims,
                 "multiply".to_owned(),
-            ))
-        } else {
-            let r_rhs = rhs.transpose();
-            let mut result_matrix = Matrix::<T>::zero(self.rows(), rhs.cols()).unwrap();
-            let res_cols = result_matrix.cols();
-
-            result_matrix
-                .matrix
-                .chunks_mut(res_cols)
-                .zip(self.matrix.chunks(self.cols()))
-                .for_each(|(row_mut, row_self)| {
-                    row_mut
-                        .iter_mut()
-                        .zip(r_rhs.matrix.chunks(r_rhs.cols()))
-                        .for_each(|(entry_mut, col_rhs)| {
-                            *entry_mut = row_self
-                                .iter()
-                                .zip(col_rhs.iter())
-                                .map(|(a, b)| a.clone() * b.clone())
-                                .sum();
-                        })
-                });
+            ));
+        }
+        Ok(mul_rows_blocked(self, &rhs.transpose(), block_size))
+    }
 
-            Ok(result_matrix)
+    /// Multiply with `rhs` like [`checked_mul`](Matrix::checked_mul), but spread the rows of the
+    /// result over one thread per available core. Every entry is summed up in the same order as
+    /// with `checked_mul`, so the result is identical, bit for bit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = Matrix::from_fn(50, 40, |i, j| (i * j) as f64 / 7.0)?;
+    /// let mat_b: Matrix<f64> = Matrix::from_fn(40, 30, |i, j| (i + j) as f64 / 3.0)?;
+    /// assert_eq!(mat_a.par_mul(&mat_b)?, mat_a.checked_mul(&mat_b)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn par_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Zero + One + Clone + Send + Sync,
+    {
+        if self.cols() != rhs.rows() {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                rhs.dims,
+                "multiply".to_owned(),
+            ));
         }
+        let r_rhs = rhs.transpose();
+        let mut result_matrix = Matrix::<T>::zero(self.rows(), rhs.cols())?;
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_rows = self.rows().div_ceil(threads);
+        std::thread::scope(|scope| {
+            let lhs_chunks = self.matrix.chunks(chunk_rows * self.cols());
+            let res_chunks = result_matrix.matrix.chunks_mut(chunk_rows * rhs.cols());
+            for (lhs, res) in lhs_chunks.zip(res_chunks) {
+                let r_rhs = &r_rhs;
+                scope.spawn(move || mul_rows_blocked_into(lhs, r_rhs, MUL_BLOCK_SIZE, res));
+            }
+        });
+        Ok(result_matrix)
+    }
+
+    /// Calculate `AᵀB` without computing the transpose of `A`. The result is the same as
+    /// `a.transpose() * b`, including the error if the dimensions do not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2; 3, 4; 5, 6};
+    /// let mat_b = matrix!{1, 0; 0, 1; 1, 1};
+    /// assert_eq!(mat_a.mul_transpose_left(&mat_b)?, (mat_a.transpose() * mat_b)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn mul_transpose_left(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Zero + One + Clone,
+    {
+        if self.rows() != rhs.rows() {
+            return Err(DimensionError::NoMatch(
+                Dimensions::new(self.cols(), self.rows()),
+                rhs.dims,
+                "multiply".to_owned(),
+            ));
+        }
+        // row i of the result is the sum of the rows of `rhs`, weighted with column i of `self`,
+        // which adds up every entry in the same order as the regular multiplication. Blocks of
+        // result rows are updated together, so every row of `rhs` is reused while it is cached.
+        let (cols, inner) = (rhs.cols(), self.rows());
+        let mut result_matrix = Matrix::<T>::zero(self.cols(), cols)?;
+        for (block, rows_res) in result_matrix
+            .matrix
+            .chunks_mut(MUL_BLOCK_SIZE * cols)
+            .enumerate()
+        {
+            let offset = block * MUL_BLOCK_SIZE;
+            for (i, row_res) in rows_res.chunks_mut(cols).enumerate() {
+                let a = &self[0][offset + i];
+                for (entry, b) in row_res.iter_mut().zip(rhs[0].iter()) {
+                    *entry = a.clone() * b.clone();
+                }
+            }
+            for k in 1..inner {
+                for (i, row_res) in rows_res.chunks_mut(cols).enumerate() {
+                    let a = &self[k][offset + i];
+                    for (entry, b) in row_res.iter_mut().zip(rhs[k].iter()) {
+                        *entry = entry.clone() + a.clone() * b.clone();
+                    }
+                }
+            }
+        }
+        Ok(result_matrix)
+    }
+
+    /// Calculate `ABᵀ` without computing the transpose of `B`. The result is the same as
+    /// `a * b.transpose()`, including the error if the dimensions do not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6};
+    /// assert_eq!(mat_a.mul_transpose_right(&mat_a)?, matrix!{14, 32; 32, 77});
+    /// # Ok(()) }
+    /// ```
+    pub fn mul_transpose_right(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Zero + One + Clone,
+    {
+        if self.cols() != rhs.cols() {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                Dimensions::new(rhs.cols(), rhs.rows()),
+                "multiply".to_owned(),
+            ));
+        }
+        Ok(mul_rows_blocked(self, rhs, MUL_BLOCK_SIZE))
+    }
+}
+
+/// Multiply `lhs` with the matrix whose columns are the rows of `r_rhs`, i.e. every entry of the
+/// result is the dot product of a row of `lhs` and a row of `r_rhs`. Rows and columns of the
+/// result are processed in blocks of `block_size`.
+fn mul_rows_blocked<T>(lhs: &Matrix<T>, r_rhs: &Matrix<T>, block_size: usize) -> Matrix<T>
+where
+    T: Zero + One + Clone,
+{
+    let mut result_matrix = Matrix::<T>::zero(lhs.rows(), r_rhs.rows()).unwrap();
+    mul_rows_blocked_into(&lhs.matrix, r_rhs, block_size, &mut result_matrix.matrix);
+    result_matrix
+}
+
+/// Write the rows of [`mul_rows_blocked`] for the rows in `lhs` into `res`, where `lhs` and `res`
+/// hold whole rows of the left-hand side and of the result. Every entry only depends on its own
+/// row, so any split into rows gives the same entries.
+fn mul_rows_blocked_into<T>(lhs: &[T], r_rhs: &Matrix<T>, block_size: usize, res: &mut [T])
+where
+    T: Zero + One + Clone,
+{
+    let (cols, inner) = (r_rhs.rows(), r_rhs.cols());
+    let rows = res.len() / cols;
+
+    for row_start in (0..rows).step_by(block_size) {
+        let row_end = (row_start + block_size).min(rows);
+        for col_start in (0..cols).step_by(block_size) {
+            let col_end = (col_start + block_size).min(cols);
+            for i in row_start..row_end {
+                let row_lhs = &lhs[i * inner..(i + 1) * inner];
+                let row_res = &mut res[i * cols..(i + 1) * cols];
+                let mut j = col_start;
+                // four entries at once, as independent sums that each keep their order
+                while j + 4 <= col_end {
+                    let col = |j: usize| &r_rhs.matrix[j * inner..(j + 1) * inner];
+                    let (c0, c1, c2, c3) = (col(j), col(j + 1), col(j + 2), col(j + 3));
+                    let a = row_lhs[0].clone();
+                    let mut acc = [
+                        a.clone() * c0[0].clone(),
+                        a.clone() * c1[0].clone(),
+                        a.clone() * c2[0].clone(),
+                        a * c3[0].clone(),
+                    ];
+                    for k in 1..inner {
+                        let a = &row_lhs[k];
+                        acc[0] = acc[0].clone() + a.clone() * c0[k].clone();
+                        acc[1] = acc[1].clone() + a.clone() * c1[k].clone();
+                        acc[2] = acc[2].clone() + a.clone() * c2[k].clone();
+                        acc[3] = acc[3].clone() + a.clone() * c3[k].clone();
+                    }
+                    row_res[j..j + 4].clone_from_slice(&acc);
+                    j += 4;
+                }
+                for (j, entry) in (j..col_end).zip(row_res[j..col_end].iter_mut()) {
+                    let col_rhs = &r_rhs.matrix[j * inner..(j + 1) * inner];
+                    *entry = (1..inner).fold(row_lhs[0].clone() * col_rhs[0].clone(), |acc, k| {
+                        acc + row_lhs[k].clone() * col_rhs[k].clone()
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Matrix multiplication of borrowed matrices, without consuming either operand.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// let mat_a = matrix!{1, 2; 3, 4};
+/// let mat_b = Matrix::one(2)?;
+/// assert_eq!(((&mat_a * &mat_b)? + &mat_a)?, &mat_a * 2);
+/// # Ok(()) }
+/// ```
+impl<T> Mul<&Matrix<T>> for &Matrix<T>
+where
+    T: Zero + One + Clone,
+{
+    type Output = Result<Matrix<T>, DimensionError>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        self.checked_mul(rhs)
+    }
+}
+
+impl<T> Mul<&Matrix<T>> for Matrix<T>
+where
+    T: Zero + One + Clone,
+{
+    type Output = Result<Matrix<T>, DimensionError>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        self.checked_mul(rhs)
+    }
+}
+
+impl<T> Mul<Matrix<T>> for &Matrix<T>
+where
+    T: Zero + One + Clone,
+{
+    type Output = Result<Matrix<T>, DimensionError>;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        self.checked_mul(&rhs)
     }
 }
 
@@ -318,7 +766,7 @@ where
 /// ```
 impl<T> Mul<Vector<T>> for Matrix<T>
 where
-    T: One + Zero + std::iter::Sum + Clone,
+    T: One + Zero + Clone,
 {
     type Output = Result<Vector<T>, DimensionError>;
 
@@ -353,6 +801,20 @@ where
     }
 }
 
+/// Scalar multiplication of a borrowed matrix.
+impl<T> Mul<T> for &Matrix<T>
+where
+    T: MulAssign + Clone,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        let mut result_matrix = self.clone();
+        result_matrix *= rhs;
+        result_matrix
+    }
+}
+
 impl<T> MulAssign<T> for Matrix<T>
 where
     T: MulAssign + Clone,
@@ -362,6 +824,118 @@ where
     }
 }
 
+impl<T> Matrix<T>
+where
+    T: Clone,
+{
+    /// Elementwise (Hadamard) product. Both matrices need to have the same dimensions.
+    ///
+    /// Unlike `*`, which is the matrix product, every entry is multiplied with the entry at the same
+    /// position in `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::{err::DimensionError, matrix, mat::Matrix};
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{1, 2, 3; 4, 5, 6};
+    /// let mat_b = matrix!{2, 0, 1; -1, 3, 2};
+    /// assert_eq!(mat_a.hadamard(&mat_b)?, matrix!{2, 0, 3; -4, 15, 12});
+    /// // the matrix product of two 2x3 matrices is not defined
+    /// assert!((mat_a * mat_b).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn hadamard(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Mul<Output = T>,
+    {
+        self.zip_with_op(rhs, "multiply elementwise", |a, b| a.clone() * b.clone())
+    }
+
+    /// Elementwise (Hadamard) product, storing the result in `self`.
+    pub fn hadamard_assign(&mut self, rhs: &Matrix<T>) -> Result<(), DimensionError>
+    where
+        T: MulAssign,
+    {
+        if self.dims != rhs.dims {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                rhs.dims,
+                "multiply elementwise".to_owned(),
+            ));
+        }
+        self.matrix
+            .iter_mut()
+            .zip(rhs.matrix.iter())
+            .for_each(|(a, b)| *a *= b.clone());
+        Ok(())
+    }
+
+    /// Elementwise division. Both matrices need to have the same dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::{err::DimensionError, matrix, mat::Matrix};
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{2.0, 9.0, 3.0; 4.0, 5.0, -6.0};
+    /// let mat_b = matrix!{2.0, 3.0, 0.5; -1.0, 5.0, 2.0};
+    /// assert_eq!(mat_a.hadamard_div(&mat_b)?, matrix!{1.0, 3.0, 6.0; -4.0, 1.0, -3.0});
+    /// # Ok(()) }
+    /// ```
+    pub fn hadamard_div(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, DimensionError>
+    where
+        T: Div<Output = T>,
+    {
+        self.zip_with_op(rhs, "divide elementwise", |a, b| a.clone() / b.clone())
+    }
+}
+
+/// Scalar operations with the scalar on the left-hand side, e.g. `2 * mat`. Generic
+/// implementations like `impl<T> Mul<Matrix<T>> for T` are not allowed, so they are implemented
+/// for the primitive number types.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::{err::DimensionError, matrix, mat::Matrix};
+/// # fn main() -> Result<(), DimensionError> {
+/// let mat_a = matrix!{1, 2; 3, 4};
+/// assert_eq!(2 * mat_a.clone(), mat_a.clone() * 2);
+/// assert_eq!(1 + mat_a.clone(), mat_a.clone() + 1);
+/// assert_eq!(10 - mat_a, matrix!{9, 8; 7, 6});
+/// # Ok(()) }
+/// ```
+macro_rules! impl_scalar_lhs_ops {
+    ($($t:ty)*) => {$(
+        impl Add<Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            fn add(self, rhs: Matrix<$t>) -> Self::Output {
+                rhs + self
+            }
+        }
+
+        impl Sub<Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            fn sub(self, rhs: Matrix<$t>) -> Self::Output {
+                rhs.map_into(|x| self - x)
+            }
+        }
+
+        impl Mul<Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            fn mul(self, rhs: Matrix<$t>) -> Self::Output {
+                rhs * self
+            }
+        }
+    )*};
+}
+
+impl_scalar_lhs_ops!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
+
 /// Elementwise division. Same as multiplying with the inverse.
 ///
 /// # Example
@@ -398,6 +972,8 @@ where
 
 /// Indexing matrices returns the corresponding row of the matrix as a slice.
 ///
+/// Panics if the row is out of bounds, use [`Matrix::get`] for an access that is checked.
+///
 /// # Example
 ///
 /// ```
@@ -447,3 +1023,39 @@ impl<T> IndexMut<usize> for Matrix<T> {
         &mut self.matrix[idx * cols..idx * cols + cols]
     }
 }
+
+/// Indexing matrices with a `(row, col)` pair returns the corresponding entry.
+///
+/// Panics if the position is out of bounds, use [`Matrix::get`] for an access that is checked.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// let mut mat = Matrix::<u32>::zero(2, 3)?;
+/// mat[(1, 2)] = 4;
+/// assert_eq!(mat[(1, 2)], 4);
+/// assert_eq!(mat[(1, 2)], mat[1][2]);
+/// # Ok(()) }
+/// ```
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        match self.get(row, col) {
+            Some(entry) => entry,
+            None => panic!("Unreachable index: ({}, {})", row, col),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        match self.get_mut(row, col) {
+            Some(entry) => entry,
+            None => panic!("Unreachable index: ({}, {})", row, col),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crate::mat::{Conjugate, Matrix};
+use num_traits::{Float, Num, One, Zero};
+
+impl<T> Matrix<T> {
+    /// Check whether the matrix is square and equal to its transpose. To allow for round-off
+    /// errors in float matrices, use [`is_hermitian`](Matrix::is_hermitian) with a tolerance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(matrix!{1, 2; 2, 3}.is_symmetric());
+    /// assert!(!matrix!{1, 2; 0, 3}.is_symmetric());
+    /// assert!(!matrix!{1, 2}.is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.is_square() && (0..self.rows()).all(|i| (0..i).all(|j| self[i][j] == self[j][i]))
+    }
+
+    /// Check whether all entries below the main diagonal are zero. Rectangular matrices are
+    /// allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(matrix!{1, 2, 3; 0, 4, 5}.is_upper_triangular());
+    /// assert!(!matrix!{1, 2; 3, 4}.is_upper_triangular());
+    /// ```
+    pub fn is_upper_triangular(&self) -> bool
+    where
+        T: Zero,
+    {
+        (0..self.rows()).all(|i| (0..i.min(self.cols())).all(|j| self[i][j].is_zero()))
+    }
+
+    /// Check whether all entries above the main diagonal are zero. Rectangular matrices are
+    /// allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(matrix!{1, 0; 2, 3; 4, 5}.is_lower_triangular());
+    /// assert!(!matrix!{1, 2; 3, 4}.is_lower_triangular());
+    /// ```
+    pub fn is_lower_triangular(&self) -> bool
+    where
+        T: Zero,
+    {
+        (0..self.rows()).all(|i| ((i + 1)..self.cols()).all(|j| self[i][j].is_zero()))
+    }
+
+    /// Check whether all entries outside of the main diagonal are zero. Rectangular matrices are
+    /// allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(matrix!{1, 0; 0, 3}.is_diagonal());
+    /// assert!(!matrix!{1, 0; 2, 3}.is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool
+    where
+        T: Zero,
+    {
+        self.is_upper_triangular() && self.is_lower_triangular()
+    }
+
+    /// Check whether the matrix is square, has ones on the main diagonal and zeros everywhere
+    /// else.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(matrix!{1, 0; 0, 1}.is_identity());
+    /// assert!(!matrix!{1, 0; 0, 2}.is_identity());
+    /// assert!(!matrix!{1, 0}.is_identity());
+    /// ```
+    pub fn is_identity(&self) -> bool
+    where
+        T: Zero + One + PartialEq,
+    {
+        self.is_square()
+            && self
+                .indexed_iter()
+                .all(|((i, j), x)| if i == j { *x == T::one() } else { x.is_zero() })
+    }
+
+    /// Check whether the matrix is square and `AᵀA` differs from the identity by at most `eps` in
+    /// every entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let (c, s) = (0.6_f64, 0.8_f64);
+    /// assert!(matrix!{c, -s; s, c}.is_orthogonal(1e-12));
+    /// assert!(!matrix!{1.0, 1.0; 0.0, 1.0}.is_orthogonal(1e-12));
+    /// ```
+    pub fn is_orthogonal(&self, eps: T) -> bool
+    where
+        T: Float,
+    {
+        let dim = self.rows();
+        self.is_square()
+            && (0..dim).all(|i| {
+                (0..dim).all(|j| {
+                    let dot = (0..dim).fold(T::zero(), |sum, k| sum + self[k][i] * self[k][j]);
+                    let expected = if i == j { T::one() } else { T::zero() };
+                    (dot - expected).abs() <= eps
+                })
+            })
+    }
+
+    /// Check whether `other` has the same dimensions and every entry differs from the
+    /// corresponding entry of `self` by at most `eps`. Equal infinities compare equal, entries that
+    /// are NaN never do.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let a = matrix!{0.1 + 0.2, 1.0};
+    /// let b = matrix!{0.3, 1.0};
+    /// assert_ne!(a, b);
+    /// assert!(a.approx_eq(&b, 1e-12));
+    /// assert!(!a.approx_eq(&matrix!{0.3; 1.0}, 1e-12));
+    /// assert!(matrix!{f64::INFINITY}.approx_eq(&matrix!{f64::INFINITY}, 1e-12));
+    /// assert!(!matrix!{f64::NAN}.approx_eq(&matrix!{f64::NAN}, 1e-12));
+    /// ```
+    pub fn approx_eq(&self, other: &Matrix<T>, eps: T) -> bool
+    where
+        T: Float,
+    {
+        self.dims == other.dims
+            && self
+                .matrix
+                .iter()
+                .zip(other.matrix.iter())
+                .all(|(&a, &b)| a == b || (a - b).abs() <= eps)
+    }
+
+    /// Check whether the matrix is square and every entry differs from the conjugate of its
+    /// mirrored entry by at most `tol`. For real entry types this checks for symmetry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// assert!(matrix!{2.0, 1.0 + 1e-14; 1.0, 3.0}.is_hermitian(1e-12));
+    /// assert!(!matrix!{2.0, 1.0; 0.0, 3.0}.is_hermitian(1e-12));
+    /// ```
+    pub fn is_hermitian(&self, tol: T::Real) -> bool
+    where
+        T: Conjugate + Clone + PartialEq + std::ops::Sub<Output = T>,
+    {
+        self.is_square()
+            && (0..self.rows()).all(|i| {
+                (0..=i).all(|j| {
+                    let conj = self[j][i].conjugate();
+                    self[i][j] == conj || (self[i][j].clone() - conj).modulus() <= tol
+                })
+            })
+    }
+
+    /// Check whether the matrix is square and `AᴴA` differs from the identity by at most `tol` in
+    /// every entry. For real entry types this checks for orthogonality.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// let (c, s) = (0.6_f64, 0.8_f64);
+    /// assert!(matrix!{c, -s; s, c}.is_unitary(1e-12));
+    /// assert!(!matrix!{1.0, 1.0; 0.0, 1.0}.is_unitary(1e-12));
+    /// ```
+    pub fn is_unitary(&self, tol: T::Real) -> bool
+    where
+        T: Conjugate + Clone + Num + std::iter::Sum,
+    {
+        let dim = self.rows();
+        self.is_square()
+            && (0..dim).all(|i| {
+                (0..dim).all(|j| {
+                    let dot: T = (0..dim)
+                        .map(|k| self[k][i].conjugate() * self[k][j].clone())
+                        .sum();
+                    let expected = if i == j { T::one() } else { T::zero() };
+                    (dot - expected).modulus() <= tol
+                })
+            })
+    }
+}
@@ -0,0 +1,370 @@
+use crate::err::{DimensionError, MatrixError};
+use crate::mat::{Matrix, PivotMagnitude, Vector};
+use num_traits::{Float, Num};
+
+impl<T> Matrix<T>
+where
+    T: Clone + Num + PivotMagnitude + std::iter::Sum,
+{
+    /// Solve the linear system `Ax = b` using the LUP decomposition of the matrix.
+    ///
+    /// Returns `None` if the matrix is singular. To solve for a right-hand side given as a matrix,
+    /// or for several right-hand sides at once, use [solve_mat](Matrix::solve_mat).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{Matrix, Vector};
+    /// # use libmat::{matrix, vector};
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{2.0, 1.0, 1.0; 4.0, -6.0, 0.0; -2.0, 7.0, 2.0};
+    /// let vec_b = vector![5.0, -2.0, 9.0];
+    /// let x = Matrix::from(mat_a.solve(&vec_b)?.unwrap());
+    /// assert!(x.approx_eq(&Matrix::from(vector![1.0, 1.0, 2.0]), 1e-12));
+    /// # Ok(()) }
+    /// ```
+    pub fn solve(&self, b: &Vector<T>) -> Result<Option<Vector<T>>, DimensionError> {
+        if b.len() != self.rows() {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                b.dims,
+                "solve".to_owned(),
+            ));
+        }
+        if let Some((lu, p)) = self.lupdecompose()? {
+            Ok(Some(lup_solve(&lu, &p, b).into()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Solve the linear system `AX = B` for a matrix `B` with any number of columns.
+    ///
+    /// The matrix is decomposed only once, then every column of `B` is solved against the
+    /// same LUP decomposition. Returns `None` if the matrix is singular.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a = matrix!{2.0, 1.0; 1.0, 3.0};
+    /// let mat_b = matrix!{3.0, 5.0, 1.0; 4.0, 10.0, -2.0};
+    /// let mat_x = matrix!{1.0, 1.0, 1.0; 1.0, 3.0, -1.0};
+    /// assert!(mat_a.solve_mat(&mat_b)?.unwrap().approx_eq(&mat_x, 1e-12));
+    /// # Ok(()) }
+    /// ```
+    pub fn solve_mat(&self, b: &Matrix<T>) -> Result<Option<Matrix<T>>, DimensionError> {
+        if b.rows() != self.rows() {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                b.dims,
+                "solve".to_owned(),
+            ));
+        }
+        if let Some((lu, p)) = self.lupdecompose()? {
+            let mut x = Matrix::<T>::zero(b.rows(), b.cols())?;
+            for j in 0..b.cols() {
+                let b_j: Vec<T> = (0..b.rows()).map(|i| b[i][j].clone()).collect();
+                for (i, x_ij) in lup_solve(&lu, &p, &b_j).into_iter().enumerate() {
+                    x[i][j] = x_ij;
+                }
+            }
+            Ok(Some(x))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Invert the matrix without consuming it.
+    ///
+    /// Unlike [`Inv::inv`](num_traits::ops::inv::Inv::inv), both failure modes are reported
+    /// through [`MatrixError`]: [`NotSquare`](MatrixError::NotSquare) for non-square matrices and
+    /// [`Singular`](MatrixError::Singular) for matrices that are not invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::MatrixError;
+    /// # fn main() -> Result<(), MatrixError> {
+    /// let mat_a: Matrix<f64> = matrix!{2.0, 1.0; 4.0, 4.0};
+    /// assert!(mat_a.try_inverse()?.approx_eq(&matrix!{1.0, -0.25; -1.0, 0.5}, 1e-12));
+    /// let mat_b: Matrix<f64> = matrix!{1.0, 2.0; 2.0, 4.0};
+    /// assert_eq!(mat_b.try_inverse(), Err(MatrixError::Singular));
+    /// let mat_c: Matrix<f64> = matrix!{1.0, 2.0};
+    /// assert_eq!(mat_c.try_inverse(), Err(MatrixError::NotSquare(mat_c.dims())));
+    /// # Ok(()) }
+    /// ```
+    pub fn try_inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        let (lu, p) = self
+            .lupdecompose()
+            .map_err(|_| MatrixError::NotSquare(self.dims))?
+            .ok_or(MatrixError::Singular)?;
+        let dim = self.rows();
+        let mut inv = self.clone();
+        for j in 0..dim {
+            let e_j: Vec<T> = (0..dim)
+                .map(|i| if i == j { T::one() } else { T::zero() })
+                .collect();
+            for (i, x_ij) in lup_solve(&lu, &p, &e_j).into_iter().enumerate() {
+                inv[i][j] = x_ij;
+            }
+        }
+        Ok(inv)
+    }
+}
+
+/// Solve `Ax = b` given the decomposition `AQ = LU` with the column permutation `p`,
+/// as returned by [Matrix::lupdecompose].
+pub(crate) fn lup_solve<T>(lu: &Matrix<T>, p: &[usize], b: &[T]) -> Vec<T>
+where
+    T: Clone + Num,
+{
+    let dim = lu.rows();
+    let mut y = b.to_vec();
+    for i in 0..dim {
+        for k in 0..i {
+            y[i] = y[i].clone() - lu[i][k].clone() * y[k].clone();
+        }
+    }
+    for i in (0..dim).rev() {
+        for k in (i + 1)..dim {
+            y[i] = y[i].clone() - lu[i][k].clone() * y[k].clone();
+        }
+        y[i] = y[i].clone() / lu[i][i].clone();
+    }
+    let mut x = y.clone();
+    for (i, y_i) in y.into_iter().enumerate() {
+        x[p[i]] = y_i;
+    }
+    x
+}
+
+impl<T> Matrix<T>
+where
+    T: Float + std::iter::Sum,
+{
+    /// Calculate the least-squares solution `x` minimizing `‖Ax − b‖₂`.
+    ///
+    /// Overdetermined systems with full column rank are solved with the QR decomposition.
+    /// Underdetermined and rank deficient systems are solved with the singular value decomposition,
+    /// which results in the solution with the smallest norm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// // fit a line through (0, 1), (1, 3), (2, 5), (3, 7)
+    /// let mat_a: Matrix<f64> = matrix!{1.0, 0.0; 1.0, 1.0; 1.0, 2.0; 1.0, 3.0};
+    /// let mat_b = matrix!{1.0; 3.0; 5.0; 7.0};
+    /// let x = mat_a.least_squares(&mat_b)?;
+    /// assert!((x[0][0] - 1.0).abs() < 1e-10);
+    /// assert!((x[1][0] - 2.0).abs() < 1e-10);
+    /// # Ok(()) }
+    /// ```
+    pub fn least_squares(&self, b: &Matrix<T>) -> Result<Matrix<T>, DimensionError> {
+        if b.rows() != self.rows() {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                b.dims,
+                "solve".to_owned(),
+            ));
+        }
+        if self.rows() >= self.cols() {
+            if let Some(x) = self.qr_least_squares(b)? {
+                return Ok(x);
+            }
+        }
+        self.pseudo_inv()? * b.clone()
+    }
+
+    /// Solve `AX = B` for a symmetric positive-definite matrix with the conjugate gradient
+    /// method. Every column of `B` is solved separately.
+    ///
+    /// A column is converged once the residual `‖b - Ax‖` is at most `tol · ‖b‖`. If some column
+    /// does not converge within `max_iter` iterations, `None` is returned. The matrix is not
+    /// checked for being symmetric positive-definite up front, but if the method breaks down
+    /// because it is not, `None` is returned as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{4.0, 1.0; 1.0, 3.0};
+    /// let b = matrix!{1.0; 2.0};
+    /// let x = mat_a.conjugate_gradient(&b, 10, 1e-12)?.unwrap();
+    /// assert!(x.approx_eq(&matrix!{1.0 / 11.0; 7.0 / 11.0}, 1e-12));
+    /// # Ok(()) }
+    /// ```
+    pub fn conjugate_gradient(
+        &self,
+        b: &Matrix<T>,
+        max_iter: usize,
+        tol: T,
+    ) -> Result<Option<Matrix<T>>, DimensionError> {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        if b.rows() != self.rows() {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                b.dims,
+                "solve".to_owned(),
+            ));
+        }
+        let mut x = Matrix::zero(b.rows(), b.cols())?;
+        for (j, col) in b.col_iter().enumerate() {
+            let col: Vec<T> = col.into_iter().cloned().collect();
+            match self.cg_column(&col, max_iter, tol) {
+                Some(x_j) => x_j.into_iter().enumerate().for_each(|(i, v)| x[i][j] = v),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(x))
+    }
+
+    /// Conjugate gradient iteration for a single right-hand side `b`.
+    fn cg_column(&self, b: &[T], max_iter: usize, tol: T) -> Option<Vec<T>> {
+        let dim = b.len();
+        let dot = |u: &[T], v: &[T]| u.iter().zip(v).map(|(a, b)| *a * *b).sum::<T>();
+        let threshold = tol * dot(b, b).sqrt();
+        let mut x = vec![T::zero(); dim];
+        let mut r = b.to_vec();
+        let mut p = r.clone();
+        let mut rr = dot(&r, &r);
+        for _ in 0..max_iter {
+            if rr.sqrt() <= threshold {
+                return Some(x);
+            }
+            let ap: Vec<T> = (0..dim).map(|i| dot(&self[i], &p)).collect();
+            let pap = dot(&p, &ap);
+            if pap <= T::zero() {
+                // only happens for matrices that are not positive-definite
+                return None;
+            }
+            let alpha = rr / pap;
+            for i in 0..dim {
+                x[i] = x[i] + alpha * p[i];
+                r[i] = r[i] - alpha * ap[i];
+            }
+            let rr_next = dot(&r, &r);
+            let beta = rr_next / rr;
+            for i in 0..dim {
+                p[i] = r[i] + beta * p[i];
+            }
+            rr = rr_next;
+        }
+        if rr.sqrt() <= threshold {
+            Some(x)
+        } else {
+            None
+        }
+    }
+
+    /// Least-squares solution using the thin QR decomposition, `None` if `R` is singular.
+    fn qr_least_squares(&self, b: &Matrix<T>) -> Result<Option<Matrix<T>>, DimensionError> {
+        let (q, r) = self.qr()?;
+        let n = self.cols();
+        let max_diag = (0..n).fold(T::zero(), |max, i| max.max(r[i][i].abs()));
+        let tol = T::epsilon() * T::from(self.rows()).unwrap() * max_diag;
+        if (0..n).any(|i| r[i][i].abs() <= tol) {
+            return Ok(None);
+        }
+        let mut x = (q.transpose() * b.clone())?;
+        for j in 0..x.cols() {
+            for i in (0..n).rev() {
+                x[i][j] = ((i + 1)..n).fold(x[i][j], |sum, k| sum - r[i][k] * x[k][j]) / r[i][i];
+            }
+        }
+        Ok(Some(x))
+    }
+
+    /// Calculate the Moore-Penrose pseudoinverse `A⁺ = V·Σ⁺·Uᵀ` using the singular value decomposition.
+    ///
+    /// Singular values below `eps * max(rows, cols) * σ_max` are treated as zero. Use
+    /// [`Matrix::pseudo_inv_with_tol`] to choose a different tolerance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{1.0, 2.0; 2.0, 4.0; 0.0, 0.0};
+    /// let pinv = mat_a.pseudo_inv()?;
+    /// assert_eq!((pinv.rows(), pinv.cols()), (2, 3));
+    /// assert!((pinv[0][0] - 0.04).abs() < 1e-12);
+    /// assert!((pinv[1][1] - 0.16).abs() < 1e-12);
+    /// # Ok(()) }
+    /// ```
+    pub fn pseudo_inv(&self) -> Result<Matrix<T>, DimensionError> {
+        let (u, s, vt) = self.svd()?;
+        let tol = T::epsilon() * T::from(self.rows().max(self.cols())).unwrap() * s[0];
+        pseudo_inv_from_svd(&u, &s, &vt, tol)
+    }
+
+    /// Calculate the Moore-Penrose pseudoinverse, treating all singular values `<= tol` as zero.
+    pub fn pseudo_inv_with_tol(&self, tol: T) -> Result<Matrix<T>, DimensionError> {
+        let (u, s, vt) = self.svd()?;
+        pseudo_inv_from_svd(&u, &s, &vt, tol)
+    }
+
+    /// Shorthand for [`Matrix::pseudo_inv`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat_a: Matrix<f64> = matrix!{1.0, 2.0; 2.0, 4.0; 0.0, 0.0};
+    /// assert_eq!(mat_a.pinv()?, mat_a.pseudo_inv()?);
+    /// # Ok(()) }
+    /// ```
+    pub fn pinv(&self) -> Result<Matrix<T>, DimensionError> {
+        self.pseudo_inv()
+    }
+
+    /// Shorthand for [`Matrix::pseudo_inv_with_tol`].
+    pub fn pinv_with_tol(&self, tol: T) -> Result<Matrix<T>, DimensionError> {
+        self.pseudo_inv_with_tol(tol)
+    }
+}
+
+/// Compute `V·Σ⁺·Uᵀ` from the singular value decomposition `(u, s, vt)`, treating all singular
+/// values `<= tol` as zero.
+fn pseudo_inv_from_svd<T>(
+    u: &Matrix<T>,
+    s: &[T],
+    vt: &Matrix<T>,
+    tol: T,
+) -> Result<Matrix<T>, DimensionError>
+where
+    T: Float + std::iter::Sum,
+{
+    let mut v = vt.transpose();
+    for (j, s_j) in s.iter().enumerate() {
+        let factor = if *s_j > tol {
+            T::one() / *s_j
+        } else {
+            T::zero()
+        };
+        for i in 0..v.rows() {
+            v[i][j] = v[i][j] * factor;
+        }
+    }
+    v * u.transpose()
+}
@@ -1,36 +1,143 @@
-use crate::err::DimensionError;
-use crate::mat::Matrix;
-use num_traits::identities::{One, Zero};
+use super::mat_io::parse_rows;
+use crate::err::{DimensionError, MatrixError, ParseMatrixError};
+use crate::mat::{Conjugate, Matrix, PivotMagnitude};
 use num_traits::ops::inv::Inv;
-use num_traits::sign::Signed;
+use num_traits::Num;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::result::Result;
+use std::str::FromStr;
 
+/// Matrices are displayed one row per line, with the entries of each column right-aligned to a
+/// common width and separated by a space. A precision (`{:.3}`) is forwarded to the entries, and
+/// a width (`{:8}`) sets the minimum width of every column.
+///
+/// The alternate form (`{:#}`) separates entries by tabs without any alignment.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// let mat = matrix!{-1.0 / 3.0, 2.0; 10.0, 0.5};
+/// assert_eq!(format!("{:.2}", mat), "-0.33 2.00\n10.00 0.50");
+/// assert_eq!(format!("{:#}", matrix!{1, 22; 333, 4}), "1\t22\n333\t4");
+/// ```
 impl<T> Display for Matrix<T>
 where
     T: Display,
 {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        for i in 0..self.rows() {
-            for j in 0..self.cols() {
-                let n = &self.matrix[i * self.cols() + j];
-                if j == self.cols() - 1 && i == self.rows() - 1 {
-                    write!(f, "{n}")?;
-                } else if j == self.cols() - 1 {
-                    writeln!(f, "{n}")?;
-                } else {
-                    write!(f, "{n}\t")?;
+        let rows: Vec<&[T]> = self.matrix.chunks(self.cols()).collect();
+        fmt_rows(f, &rows)
+    }
+}
+
+/// Write `rows` as described for the [`Display`] implementation of [`Matrix`].
+pub(crate) fn fmt_rows<T>(f: &mut Formatter, rows: &[&[T]]) -> fmt::Result
+where
+    T: Display,
+{
+    let entries: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|n| match f.precision() {
+                    Some(precision) => format!("{n:.precision$}"),
+                    None => format!("{n}"),
+                })
+                .collect()
+        })
+        .collect();
+    let alternate = f.alternate();
+    let mut widths = vec![f.width().unwrap_or(0); rows.first().map_or(0, |row| row.len())];
+    for row in entries.iter() {
+        for (width, entry) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(entry.chars().count());
+        }
+    }
+    for (i, row) in entries.iter().enumerate() {
+        if i > 0 {
+            writeln!(f)?;
+        }
+        for (j, entry) in row.iter().enumerate() {
+            if alternate {
+                if j > 0 {
+                    write!(f, "\t")?;
                 }
+                write!(f, "{entry}")?;
+            } else {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{entry:>width$}", width = widths[j])?;
             }
         }
-        Ok(())
+    }
+    Ok(())
+}
+
+/// Parse a matrix in the format produced by its [`Display`] implementation: one row per line,
+/// entries separated by tabs or spaces. Trailing whitespace after the last row is ignored. Rows of
+/// differing lengths result in [`DimensionError::Ragged`] with the line counted from one.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// # use libmat::err::ParseMatrixError;
+/// # fn main() -> Result<(), ParseMatrixError> {
+/// let mat = matrix!{1, 2, 3; 4, 5, 6};
+/// assert_eq!(mat.to_string().parse::<Matrix<i32>>()?, mat);
+/// assert_eq!(
+///     "1 2\n3 x".parse::<Matrix<i32>>(),
+///     Err(ParseMatrixError::InvalidEntry(1, 1, "x".to_owned()))
+/// );
+/// # Ok(()) }
+/// ```
+impl<T> FromStr for Matrix<T>
+where
+    T: FromStr,
+{
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Matrix<T>, ParseMatrixError> {
+        parse_rows(s, None)
+    }
+}
+
+/// Consume the matrix and iterate over its entries in row-major order.
+impl<T> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.matrix.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Matrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
 impl<T> Inv for Matrix<T>
 where
-    T: One + Zero + Clone + Signed + PartialOrd + std::iter::Sum + std::ops::DivAssign,
+    T: Clone + Num + PivotMagnitude + std::iter::Sum + std::ops::DivAssign,
 {
     type Output = Result<Option<Matrix<T>>, DimensionError>;
 
@@ -47,44 +154,68 @@ where
     /// let mat_a: Matrix<f32> = matrix!{{0.0,-1.0,2.0},{1.0,2.0,0.0},{2.0,1.0,0.0}};
     /// let mat_c: Matrix<i32> = matrix!{{1,0,0},{0,1,0},{0,0,0}}; // not invertible
     /// let mat_b = matrix!{{0.0, -1.0/3.0, 2.0/3.0}, {0.0, 2.0/3.0, -1.0/3.0}, {1.0/2.0, 1.0/3.0, -1.0/6.0}};
-    /// assert_eq!(mat_a.inv()?, Some(mat_b));
+    /// assert!(mat_a.inv()?.unwrap().approx_eq(&mat_b, 1e-6));
     /// assert_eq!(mat_c.inv()?, None);
     /// # Ok(()) }
     /// ```
     fn inv(self) -> Self::Output {
-        if let Some((mat, p)) = self.lupdecompose()? {
-            let dim = mat.rows();
-            let mut mat_inv = Matrix::<T>::zero(dim, dim).unwrap();
-            for j in 0..dim {
-                for i in 0..dim {
-                    mat_inv[i][j] = {
-                        if p[i] == j {
-                            T::one()
-                        } else {
-                            T::zero()
-                        }
-                    };
-
-                    for k in 0..i {
-                        mat_inv[i][j] =
-                            mat_inv[i][j].clone() - mat[i][k].clone() * mat_inv[k][j].clone();
-                    }
+        match self.try_inverse() {
+            Ok(inv) => Ok(Some(inv)),
+            Err(MatrixError::Singular) => Ok(None),
+            Err(_) => Err(DimensionError::NoSquare),
+        }
+    }
+}
+
+macro_rules! impl_pivot_magnitude {
+    ($($t:ty),*) => {
+        $(
+            impl PivotMagnitude for $t {
+                type Magnitude = $t;
+
+                fn pivot_magnitude(&self) -> $t {
+                    self.abs()
                 }
+            }
+        )*
+    };
+}
 
-                for i in (0..dim).rev() {
-                    for k in (i + 1)..dim {
-                        mat_inv[i][j] =
-                            mat_inv[i][j].clone() - mat[i][k].clone() * mat_inv[k][j].clone();
-                    }
-                    mat_inv[i][j] /= mat[i][i].clone();
+impl_pivot_magnitude!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+macro_rules! impl_conjugate {
+    ($($t:ty),*; $($i:ty => $u:ty),*) => {
+        $(
+            impl Conjugate for $t {
+                type Real = $t;
+
+                fn conjugate(&self) -> $t {
+                    *self
+                }
+
+                fn modulus(&self) -> $t {
+                    self.abs()
                 }
             }
-            if (p[dim] - dim) % 2 != 0 {
-                mat_inv.matrix.reverse();
+        )*
+        $(
+            // the unsigned absolute value does not overflow for the minimum value
+            impl Conjugate for $i {
+                type Real = $u;
+
+                fn conjugate(&self) -> $i {
+                    *self
+                }
+
+                fn modulus(&self) -> $u {
+                    self.unsigned_abs()
+                }
             }
-            Ok(Some(mat_inv))
-        } else {
-            Ok(None)
-        }
-    }
+        )*
+    };
 }
+
+impl_conjugate!(
+    f32, f64;
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
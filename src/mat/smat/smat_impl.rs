@@ -135,6 +135,8 @@ where
         }
         Some((a, p))
     }
+    // `is_multiple_of` would raise the minimum supported Rust version
+    #[allow(clippy::manual_is_multiple_of)]
     fn det_approx(&self) -> f64
     where
         T: Signed,
@@ -158,28 +160,24 @@ where
         T: Copy + Signed,
     {
         if N < 4 {
-            match {
-                if N == 1 {
-                    self[0][0].to_f64()
-                } else if N == 2 {
-                    { self[0][0] * self[1][1] - self[0][1] * self[1][0] }.to_f64()
-                } else if N == 3 {
-                    {
-                        self[0][0] * self[1][1] * self[2][2]
-                            + self[1][0] * self[2][1] * self[0][2]
-                            + self[2][0] * self[0][1] * self[1][2]
-                            - self[0][2] * self[1][1] * self[2][0]
-                            - self[0][1] * self[1][0] * self[2][2]
-                            - self[0][0] * self[1][2] * self[2][1]
-                    }
-                    .to_f64()
-                } else {
-                    Some(f64::default())
+            let det = if N == 1 {
+                self[0][0].to_f64()
+            } else if N == 2 {
+                { self[0][0] * self[1][1] - self[0][1] * self[1][0] }.to_f64()
+            } else if N == 3 {
+                {
+                    self[0][0] * self[1][1] * self[2][2]
+                        + self[1][0] * self[2][1] * self[0][2]
+                        + self[2][0] * self[0][1] * self[1][2]
+                        - self[0][2] * self[1][1] * self[2][0]
+                        - self[0][1] * self[1][0] * self[2][2]
+                        - self[0][0] * self[1][2] * self[2][1]
                 }
-            } {
-                Some(n) => n,
-                None => f64::default(),
-            }
+                .to_f64()
+            } else {
+                Some(f64::default())
+            };
+            det.unwrap_or_default()
         } else {
             self.det_approx()
         }
@@ -0,0 +1,328 @@
+use crate::err::DimensionError;
+use crate::mat::dims::Dimensions;
+use crate::mat::{Matrix, Vector};
+use num_traits::Zero;
+use std::ops::{Add, Mul};
+
+/// Sparse matrix in compressed sparse row (CSR) format.
+///
+/// Only the nonzero entries are stored, row by row. Entries that are zero are never stored, so
+/// two sparse matrices are equal exactly if their dense counterparts are equal.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::{sparse::SparseMatrix, Matrix, Vector};
+/// # use libmat::{matrix, vector};
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// let sparse = SparseMatrix::from_triplets(2, 3, &[(0, 0, 1), (1, 2, 2), (0, 0, 3)])?;
+/// assert_eq!(sparse.nnz(), 2);
+/// assert_eq!(sparse.to_dense(), matrix!{4, 0, 0; 0, 0, 2});
+/// assert_eq!((&sparse * &vector![1, 1, 1])?, vector![4, 2]);
+/// # Ok(()) }
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct SparseMatrix<T> {
+    dims: Dimensions,
+    row_ptr: Vec<usize>,
+    col_idx: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> SparseMatrix<T> {
+    pub fn rows(&self) -> usize {
+        self.dims.rows()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.dims.cols()
+    }
+
+    pub fn dims(&self) -> Dimensions {
+        self.dims
+    }
+
+    /// The number of stored, i.e. nonzero, entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Iterate over the stored entries as `(row, col, value)` in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        (0..self.rows()).flat_map(move |i| {
+            (self.row_ptr[i]..self.row_ptr[i + 1])
+                .map(move |k| (i, self.col_idx[k], &self.values[k]))
+        })
+    }
+}
+
+impl<T> SparseMatrix<T>
+where
+    T: Clone + Zero,
+{
+    /// Construct a sparse matrix with dimensions `rows x cols` from `(row, col, value)` triplets.
+    /// Triplets for the same position are summed up, and entries that end up zero are dropped.
+    ///
+    /// Triplets outside of the matrix result in [`DimensionError::OutOfBounds`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{sparse::SparseMatrix, Matrix};
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let sparse = SparseMatrix::from_triplets(2, 2, &[(1, 0, 5), (0, 1, 2), (0, 1, -2)])?;
+    /// assert_eq!(sparse.nnz(), 1);
+    /// assert_eq!(sparse.to_dense(), matrix!{0, 0; 5, 0});
+    /// assert_eq!(
+    ///     SparseMatrix::from_triplets(2, 2, &[(0, 2, 1)]),
+    ///     Err(DimensionError::OutOfBounds(2, 2))
+    /// );
+    /// # Ok(()) }
+    /// ```
+    pub fn from_triplets(
+        rows: usize,
+        cols: usize,
+        triplets: &[(usize, usize, T)],
+    ) -> Result<SparseMatrix<T>, DimensionError> {
+        if rows < 1 || cols < 1 {
+            return Err(DimensionError::InvalidDimensions);
+        }
+        for (row, col, _) in triplets {
+            if *row >= rows {
+                return Err(DimensionError::OutOfBounds(*row, rows));
+            }
+            if *col >= cols {
+                return Err(DimensionError::OutOfBounds(*col, cols));
+            }
+        }
+        let mut sorted: Vec<&(usize, usize, T)> = triplets.iter().collect();
+        sorted.sort_by_key(|(row, col, _)| (*row, *col));
+        let mut merged: Vec<(usize, usize, T)> = Vec::with_capacity(sorted.len());
+        for (row, col, value) in sorted {
+            match merged.last_mut() {
+                Some((last_row, last_col, sum)) if (*last_row, *last_col) == (*row, *col) => {
+                    *sum = sum.clone() + value.clone()
+                }
+                _ => merged.push((*row, *col, value.clone())),
+            }
+        }
+
+        let mut row_ptr = vec![0; rows + 1];
+        let mut col_idx = Vec::with_capacity(merged.len());
+        let mut values = Vec::with_capacity(merged.len());
+        for (row, col, value) in merged.into_iter().filter(|(_, _, x)| !x.is_zero()) {
+            row_ptr[row + 1] += 1;
+            col_idx.push(col);
+            values.push(value);
+        }
+        for i in 0..rows {
+            row_ptr[i + 1] += row_ptr[i];
+        }
+        Ok(SparseMatrix {
+            dims: Dimensions::new(rows, cols),
+            row_ptr,
+            col_idx,
+            values,
+        })
+    }
+
+    /// Construct a sparse matrix from the nonzero entries of `mat`.
+    pub fn from_dense(mat: &Matrix<T>) -> SparseMatrix<T> {
+        let mut row_ptr = Vec::with_capacity(mat.rows() + 1);
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+        row_ptr.push(0);
+        for row in mat.row_iter() {
+            for (j, x) in row.iter().enumerate() {
+                if !x.is_zero() {
+                    col_idx.push(j);
+                    values.push(x.clone());
+                }
+            }
+            row_ptr.push(values.len());
+        }
+        SparseMatrix {
+            dims: mat.dims(),
+            row_ptr,
+            col_idx,
+            values,
+        }
+    }
+
+    /// Convert to a dense matrix.
+    pub fn to_dense(&self) -> Matrix<T> {
+        let cols = self.cols();
+        let mut entries = vec![T::zero(); self.rows() * cols];
+        for (i, j, x) in self.iter() {
+            entries[i * cols + j] = x.clone();
+        }
+        Matrix {
+            dims: self.dims,
+            matrix: entries,
+        }
+    }
+
+    /// Transpose the sparse matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::{sparse::SparseMatrix, Matrix};
+    /// # use libmat::matrix;
+    /// let mat = matrix!{1, 0, 2; 0, 3, 0};
+    /// assert_eq!(SparseMatrix::from_dense(&mat).transpose().to_dense(), mat.transpose());
+    /// ```
+    pub fn transpose(&self) -> SparseMatrix<T> {
+        let (rows, cols) = (self.rows(), self.cols());
+        // count the entries of every column, which become the rows of the transpose
+        let mut row_ptr = vec![0; cols + 1];
+        for j in self.col_idx.iter() {
+            row_ptr[j + 1] += 1;
+        }
+        for j in 0..cols {
+            row_ptr[j + 1] += row_ptr[j];
+        }
+        let mut next = row_ptr.clone();
+        let mut col_idx = vec![0; self.nnz()];
+        let mut values = vec![T::zero(); self.nnz()];
+        for (i, j, x) in self.iter() {
+            col_idx[next[j]] = i;
+            values[next[j]] = x.clone();
+            next[j] += 1;
+        }
+        SparseMatrix {
+            dims: Dimensions::new(cols, rows),
+            row_ptr,
+            col_idx,
+            values,
+        }
+    }
+}
+
+impl<T> SparseMatrix<T>
+where
+    T: Clone + Zero + Mul<Output = T>,
+{
+    /// The dot product of row `i` with the dense `vec`.
+    fn row_dot(&self, i: usize, vec: &[T]) -> T {
+        (self.row_ptr[i]..self.row_ptr[i + 1]).fold(T::zero(), |sum, k| {
+            sum + self.values[k].clone() * vec[self.col_idx[k]].clone()
+        })
+    }
+}
+
+/// Sparse matrices can be multiplied with column vectors, where the length of the vector needs
+/// to match the column count of the matrix.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::{sparse::SparseMatrix, Matrix, Vector};
+/// # use libmat::{matrix, vector};
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// let mat = matrix!{1, 0, 2; 0, 3, 0};
+/// let vec = vector![1, 2, 3];
+/// assert_eq!((&SparseMatrix::from_dense(&mat) * &vec)?, (mat * vec)?);
+/// # Ok(()) }
+/// ```
+impl<T> Mul<&Vector<T>> for &SparseMatrix<T>
+where
+    T: Clone + Zero + Mul<Output = T>,
+{
+    type Output = Result<Vector<T>, DimensionError>;
+
+    fn mul(self, vec: &Vector<T>) -> Self::Output {
+        if vec.dims != Dimensions::new(self.cols(), 1) {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                vec.dims,
+                "multiply".to_owned(),
+            ));
+        }
+        let res: Vec<T> = (0..self.rows()).map(|i| self.row_dot(i, vec)).collect();
+        Ok(res.into())
+    }
+}
+
+/// Sparse matrices of the same dimensions can be added. Entries that cancel out are dropped.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::{sparse::SparseMatrix, Matrix};
+/// # use libmat::matrix;
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// let a = SparseMatrix::from_dense(&matrix!{1, 0; 0, 2});
+/// let b = SparseMatrix::from_dense(&matrix!{0, 3; 0, -2});
+/// let sum = (&a + &b)?;
+/// assert_eq!(sum.nnz(), 2);
+/// assert_eq!(sum.to_dense(), matrix!{1, 3; 0, 0});
+/// # Ok(()) }
+/// ```
+impl<T> Add<&SparseMatrix<T>> for &SparseMatrix<T>
+where
+    T: Clone + Zero,
+{
+    type Output = Result<SparseMatrix<T>, DimensionError>;
+
+    fn add(self, rhs: &SparseMatrix<T>) -> Self::Output {
+        if self.dims != rhs.dims {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                rhs.dims,
+                "add".to_owned(),
+            ));
+        }
+        let mut row_ptr = Vec::with_capacity(self.rows() + 1);
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+        row_ptr.push(0);
+        for i in 0..self.rows() {
+            // merge the two sorted rows
+            let (mut a, a_end) = (self.row_ptr[i], self.row_ptr[i + 1]);
+            let (mut b, b_end) = (rhs.row_ptr[i], rhs.row_ptr[i + 1]);
+            while a < a_end || b < b_end {
+                let col_a = if a < a_end {
+                    self.col_idx[a]
+                } else {
+                    usize::MAX
+                };
+                let col_b = if b < b_end {
+                    rhs.col_idx[b]
+                } else {
+                    usize::MAX
+                };
+                let (col, value) = if col_a < col_b {
+                    a += 1;
+                    (col_a, self.values[a - 1].clone())
+                } else if col_b < col_a {
+                    b += 1;
+                    (col_b, rhs.values[b - 1].clone())
+                } else {
+                    a += 1;
+                    b += 1;
+                    (
+                        col_a,
+                        self.values[a - 1].clone() + rhs.values[b - 1].clone(),
+                    )
+                };
+                if !value.is_zero() {
+                    col_idx.push(col);
+                    values.push(value);
+                }
+            }
+            row_ptr.push(values.len());
+        }
+        Ok(SparseMatrix {
+            dims: self.dims,
+            row_ptr,
+            col_idx,
+            values,
+        })
+    }
+}
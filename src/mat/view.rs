@@ -0,0 +1,3 @@
+mod view_impl;
+mod view_ops;
+mod view_traits;
@@ -0,0 +1,241 @@
+use crate::err::DimensionError;
+use crate::mat::_mat::{frobenius_norm, inf_norm, max_norm, one_norm, resolve_range};
+use crate::mat::dims::Dimensions;
+use crate::mat::{Matrix, MatrixView, MatrixViewMut};
+use num_traits::{Float, Signed, Zero};
+use std::ops::RangeBounds;
+
+impl<'a, T> MatrixView<'a, T> {
+    pub fn rows(&self) -> usize {
+        self.dims.rows()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.dims.cols()
+    }
+
+    pub fn dims(&self) -> Dimensions {
+        self.dims
+    }
+
+    /// Get a reference to the entry in row `row` and column `col` of the view, or `None` if the
+    /// position is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row < self.rows() && col < self.cols() {
+            self.data.get(row * self.stride + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.dims.is_square()
+    }
+
+    /// Borrow a block of the view without copying it. The ranges are relative to the view and
+    /// handled like in [`Matrix::submatrix`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9};
+    /// let view = mat.view(1.., ..)?;
+    /// assert_eq!(view.view(.., 1..)?.to_matrix(), matrix!{5, 6; 8, 9});
+    /// # Ok(()) }
+    /// ```
+    pub fn view<R, C>(&self, rows: R, cols: C) -> Result<MatrixView<'a, T>, DimensionError>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (row_start, row_end) = resolve_range(&rows, self.rows())?;
+        let (col_start, col_end) = resolve_range(&cols, self.cols())?;
+        let stride = self.stride;
+        Ok(MatrixView {
+            dims: Dimensions::new(row_end - row_start, col_end - col_start),
+            stride,
+            data: &self.data[row_start * stride + col_start..(row_end - 1) * stride + col_end],
+        })
+    }
+
+    /// Iterate over the rows of the view as slices.
+    pub fn row_iter(&self) -> impl Iterator<Item = &'a [T]> {
+        let (stride, cols, data) = (self.stride, self.cols(), self.data);
+        (0..self.rows()).map(move |i| &data[i * stride..i * stride + cols])
+    }
+
+    /// Iterate over the entries of the view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.row_iter().flat_map(|row| row.iter())
+    }
+
+    /// Iterate over the columns of the view.
+    pub fn col_iter(&self) -> impl Iterator<Item = Vec<&'a T>> + '_ {
+        (0..self.cols()).map(move |j| self.row_iter().map(|row| &row[j]).collect())
+    }
+
+    /// Calculate the sum of the diagonal entries, see [`Matrix::trace`].
+    pub fn trace(&self) -> Result<T, DimensionError>
+    where
+        T: Clone + Zero,
+    {
+        if !self.is_square() {
+            return Err(DimensionError::NoSquare);
+        }
+        Ok(self
+            .row_iter()
+            .enumerate()
+            .fold(T::zero(), |sum, (i, row)| sum + row[i].clone()))
+    }
+
+    /// Copy the entries of the view into a new matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mat = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9};
+    /// assert_eq!(mat.view(.., 1..2)?.to_matrix(), mat.submatrix(.., 1..2)?);
+    /// # Ok(()) }
+    /// ```
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix {
+            dims: self.dims,
+            matrix: self.iter().cloned().collect(),
+        }
+    }
+}
+
+/// Norms of views, see the norms of [`Matrix`].
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// let mat: Matrix<f64> = matrix!{9.0, 1.0, -2.0; 9.0, -3.0, 4.0};
+/// let view = mat.view(.., 1..)?;
+/// assert_eq!(view.frobenius_norm(), 30_f64.sqrt());
+/// assert_eq!(view.one_norm(), 6.0);
+/// assert_eq!(view.inf_norm(), 7.0);
+/// assert_eq!(view.max_norm(), 4.0);
+/// # Ok(()) }
+/// ```
+impl<'a, T> MatrixView<'a, T>
+where
+    T: Signed + PartialOrd + Clone,
+{
+    /// Calculate the 1-norm, which is the maximum absolute column sum.
+    pub fn one_norm(&self) -> T {
+        one_norm(self.row_iter(), self.cols(), T::abs)
+    }
+
+    /// Calculate the infinity-norm, which is the maximum absolute row sum.
+    pub fn inf_norm(&self) -> T {
+        inf_norm(self.row_iter(), T::abs)
+    }
+
+    /// Calculate the max-norm, which is the maximum absolute value of all entries.
+    pub fn max_norm(&self) -> T {
+        max_norm(self.row_iter(), T::abs)
+    }
+}
+
+impl<'a, T> MatrixView<'a, T>
+where
+    T: Float,
+{
+    /// Calculate the Frobenius norm, which is the square root of the sum of all squared entries.
+    pub fn frobenius_norm(&self) -> T {
+        frobenius_norm(self.row_iter())
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    pub fn rows(&self) -> usize {
+        self.dims.rows()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.dims.cols()
+    }
+
+    pub fn dims(&self) -> Dimensions {
+        self.dims
+    }
+
+    /// Get a reference to the entry in row `row` and column `col` of the view, or `None` if the
+    /// position is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows() && col < self.cols() {
+            self.data.get(row * self.stride + col)
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the entry in row `row` and column `col` of the view, or `None`
+    /// if the position is out of bounds.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.rows() && col < self.cols() {
+            self.data.get_mut(row * self.stride + col)
+        } else {
+            None
+        }
+    }
+
+    /// Reborrow the view immutably.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            dims: self.dims,
+            stride: self.stride,
+            data: self.data,
+        }
+    }
+
+    /// Apply `f` to every entry of the view in place, in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use libmat::mat::Matrix;
+    /// # use libmat::matrix;
+    /// # use libmat::err::DimensionError;
+    /// # fn main() -> Result<(), DimensionError> {
+    /// let mut mat = matrix!{1, 2, 3; 4, 5, 6};
+    /// mat.view_mut(.., 1..)?.map_mut(|x| *x *= 10);
+    /// assert_eq!(mat, matrix!{1, 20, 30; 4, 50, 60});
+    /// # Ok(()) }
+    /// ```
+    pub fn map_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        let (stride, cols) = (self.stride, self.cols());
+        for i in 0..self.rows() {
+            self.data[i * stride..i * stride + cols]
+                .iter_mut()
+                .for_each(&mut f);
+        }
+    }
+
+    /// Copy the entries of the view into a new matrix.
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        self.as_view().to_matrix()
+    }
+}
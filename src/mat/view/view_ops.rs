@@ -0,0 +1,206 @@
+use crate::err::DimensionError;
+use crate::mat::{Matrix, MatrixView, MatrixViewMut};
+use num_traits::identities::{One, Zero};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Sub, SubAssign};
+
+/// Elementwise addition of two views with the same dimensions.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// let mat = matrix!{1, 2, 3; 4, 5, 6};
+/// let sum = (mat.view(.., ..2)? + mat.view(.., 1..)?)?;
+/// assert_eq!(sum, matrix!{3, 5; 9, 11});
+/// # Ok(()) }
+/// ```
+impl<'a, 'b, T> Add<MatrixView<'b, T>> for MatrixView<'a, T>
+where
+    T: AddAssign + Clone,
+{
+    type Output = Result<Matrix<T>, DimensionError>;
+
+    fn add(self, rhs: MatrixView<'b, T>) -> Self::Output {
+        if self.dims != rhs.dims {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                rhs.dims,
+                "add".to_owned(),
+            ));
+        }
+        let mut result_matrix = self.to_matrix();
+        result_matrix
+            .iter_mut()
+            .zip(rhs.iter())
+            .for_each(|(a, b)| *a += b.clone());
+        Ok(result_matrix)
+    }
+}
+
+impl<'a, T> Add<Matrix<T>> for MatrixView<'a, T>
+where
+    T: AddAssign + Clone,
+{
+    type Output = Result<Matrix<T>, DimensionError>;
+
+    fn add(self, rhs: Matrix<T>) -> Self::Output {
+        self + rhs.view(.., ..)?
+    }
+}
+
+/// Elementwise subtraction of two views with the same dimensions.
+impl<'a, 'b, T> Sub<MatrixView<'b, T>> for MatrixView<'a, T>
+where
+    T: SubAssign + Clone,
+{
+    type Output = Result<Matrix<T>, DimensionError>;
+
+    fn sub(self, rhs: MatrixView<'b, T>) -> Self::Output {
+        if self.dims != rhs.dims {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                rhs.dims,
+                "subtract".to_owned(),
+            ));
+        }
+        let mut result_matrix = self.to_matrix();
+        result_matrix
+            .iter_mut()
+            .zip(rhs.iter())
+            .for_each(|(a, b)| *a -= b.clone());
+        Ok(result_matrix)
+    }
+}
+
+impl<'a, T> Sub<Matrix<T>> for MatrixView<'a, T>
+where
+    T: SubAssign + Clone,
+{
+    type Output = Result<Matrix<T>, DimensionError>;
+
+    fn sub(self, rhs: Matrix<T>) -> Self::Output {
+        self - rhs.view(.., ..)?
+    }
+}
+
+/// Matrix multiplication of two views. The number of columns of the left view needs to be the
+/// number of rows of the right view.
+///
+/// # Example
+///
+/// ```
+/// # use libmat::mat::Matrix;
+/// # use libmat::matrix;
+/// # use libmat::err::DimensionError;
+/// # fn main() -> Result<(), DimensionError> {
+/// let mat = matrix!{1, 2, 3; 4, 5, 6; 7, 8, 9};
+/// let prod = (mat.view(..1, ..)? * mat.view(.., 2..)?)?;
+/// assert_eq!(prod, matrix!{42});
+/// # Ok(()) }
+/// ```
+impl<'a, 'b, T> Mul<MatrixView<'b, T>> for MatrixView<'a, T>
+where
+    T: Zero + One + Clone + std::iter::Sum,
+{
+    type Output = Result<Matrix<T>, DimensionError>;
+
+    fn mul(self, rhs: MatrixView<'b, T>) -> Self::Output {
+        if self.cols() != rhs.rows() {
+            return Err(DimensionError::NoMatch(
+                self.dims,
+                rhs.dims,
+                "multiply".to_owned(),
+            ));
+        }
+        let mut entries = Vec::with_capacity(self.rows() * rhs.cols());
+        for row in self.row_iter() {
+            for j in 0..rhs.cols() {
+                entries.push(
+                    row.iter()
+                        .zip(rhs.row_iter())
+                        .map(|(a, rhs_row)| a.clone() * rhs_row[j].clone())
+                        .sum(),
+                );
+            }
+        }
+        Matrix::from_vec(self.rows(), rhs.cols(), entries)
+    }
+}
+
+impl<'a, T> Mul<Matrix<T>> for MatrixView<'a, T>
+where
+    T: Zero + One + Clone + std::iter::Sum,
+{
+    type Output = Result<Matrix<T>, DimensionError>;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        self * rhs.view(.., ..)?
+    }
+}
+
+/// Indexing a view returns the corresponding row of the view as a slice.
+impl<'a, T> Index<usize> for MatrixView<'a, T> {
+    type Output = [T];
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        if idx >= self.rows() {
+            panic!("Unreachable index: {}", idx);
+        }
+        &self.data[idx * self.stride..idx * self.stride + self.cols()]
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatrixView<'a, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        match self.get(row, col) {
+            Some(entry) => entry,
+            None => panic!("Unreachable index: ({}, {})", row, col),
+        }
+    }
+}
+
+impl<'a, T> Index<usize> for MatrixViewMut<'a, T> {
+    type Output = [T];
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        if idx >= self.rows() {
+            panic!("Unreachable index: {}", idx);
+        }
+        &self.data[idx * self.stride..idx * self.stride + self.cols()]
+    }
+}
+
+impl<'a, T> IndexMut<usize> for MatrixViewMut<'a, T> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        if idx >= self.rows() {
+            panic!("Unreachable index: {}", idx);
+        }
+        let (stride, cols) = (self.stride, self.cols());
+        &mut self.data[idx * stride..idx * stride + cols]
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatrixViewMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        match self.get(row, col) {
+            Some(entry) => entry,
+            None => panic!("Unreachable index: ({}, {})", row, col),
+        }
+    }
+}
+
+impl<'a, T> IndexMut<(usize, usize)> for MatrixViewMut<'a, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        match self.get_mut(row, col) {
+            Some(entry) => entry,
+            None => panic!("Unreachable index: ({}, {})", row, col),
+        }
+    }
+}
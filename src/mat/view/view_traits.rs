@@ -0,0 +1,33 @@
+use crate::mat::_mat::fmt_rows;
+use crate::mat::{MatrixView, MatrixViewMut};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+impl<'a, T> Clone for MatrixView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for MatrixView<'a, T> {}
+
+/// Views are displayed like matrices, see the [`Display`] implementation of
+/// [`Matrix`](crate::mat::Matrix).
+impl<'a, T> Display for MatrixView<'a, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let rows: Vec<&[T]> = self.row_iter().collect();
+        fmt_rows(f, &rows)
+    }
+}
+
+impl<'a, T> Display for MatrixViewMut<'a, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
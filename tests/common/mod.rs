@@ -0,0 +1,51 @@
+// Fixtures shared by the integration tests. Not every test uses all of them.
+#![allow(dead_code)]
+
+use libmat::mat::Matrix;
+
+/// Deterministic pseudo-random matrix with entries in `[-1, 1)`.
+pub fn random(rows: usize, cols: usize, seed: u64) -> Matrix<f64> {
+    let mut state = seed;
+    Matrix::from_fn(rows, cols, |_, _| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+    })
+    .unwrap()
+}
+
+/// Assert that `a` and `b` have the same dimensions and every entry differs by less than `eps`.
+pub fn assert_mat_eq(a: &Matrix<f64>, b: &Matrix<f64>, eps: f64) {
+    assert_eq!(a.dims(), b.dims());
+    for i in 0..a.rows() {
+        for j in 0..a.cols() {
+            assert!(
+                (a[i][j] - b[i][j]).abs() < eps,
+                "entry ({}, {}) differs: {} != {}",
+                i,
+                j,
+                a[i][j],
+                b[i][j]
+            );
+        }
+    }
+}
+
+/// Assert that `a` and `b` have the same dimensions and every entry differs by at most `eps`
+/// relative to the entry of `b`, or absolutely for entries of `b` smaller than one.
+pub fn assert_mat_rel_eq(a: &Matrix<f64>, b: &Matrix<f64>, eps: f64) {
+    assert_eq!(a.dims(), b.dims());
+    for i in 0..a.rows() {
+        for j in 0..a.cols() {
+            assert!(
+                (a[i][j] - b[i][j]).abs() <= eps * b[i][j].abs().max(1.0),
+                "entry ({}, {}) differs: {} != {}",
+                i,
+                j,
+                a[i][j],
+                b[i][j]
+            );
+        }
+    }
+}
@@ -1,11 +1,185 @@
-use libmat::{err::DimensionError, mat::Matrix, matrix};
+mod common;
+
+use common::assert_mat_eq;
+use libmat::{
+    err::DimensionError,
+    mat::{Conjugate, Matrix, PivotMagnitude},
+    matrix,
+};
 use num_traits::Inv;
 
 #[test]
 fn double_inverse() -> Result<(), DimensionError> {
     let mat_a = matrix! {{1.0, 2.0},{3.0,4.0}};
     let mat_b = matrix! {{-2.0,1.0},{1.5,-0.5}};
-    assert_eq!(mat_a.clone().inv()?, Some(mat_b.clone()));
-    assert_eq!(mat_b.inv()?, Some(mat_a));
+    assert_mat_eq(&mat_a.clone().inv()?.unwrap(), &mat_b, 1e-12);
+    assert_mat_eq(&mat_b.inv()?.unwrap(), &mat_a, 1e-12);
+    Ok(())
+}
+
+#[test]
+fn inverse_with_pivoting() -> Result<(), DimensionError> {
+    let mat_a = matrix! {{1.0, 5.0, 2.0}, {3.0, 1.0, 4.0}, {2.0, 2.0, 9.0}};
+    let mat_b = matrix! {{1.0, 2.0, 7.0}, {3.0, 1.0, 4.0}, {2.0, 8.0, 1.0}};
+    for mat in [mat_a, mat_b].iter() {
+        let prod = (mat.clone() * mat.clone().inv()?.unwrap())?;
+        let id: Matrix<f64> = Matrix::one(3)?;
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((prod[i][j] - id[i][j]).abs() < 1e-12);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Minimal complex number type, which is neither ordered nor `Signed`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+fn c(re: f64, im: f64) -> Complex {
+    Complex { re, im }
+}
+
+impl std::ops::Add for Complex {
+    type Output = Complex;
+    fn add(self, rhs: Complex) -> Complex {
+        c(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl std::ops::Sub for Complex {
+    type Output = Complex;
+    fn sub(self, rhs: Complex) -> Complex {
+        c(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl std::ops::Mul for Complex {
+    type Output = Complex;
+    fn mul(self, rhs: Complex) -> Complex {
+        c(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl std::ops::Div for Complex {
+    type Output = Complex;
+    fn div(self, rhs: Complex) -> Complex {
+        let norm_sqr = rhs.re * rhs.re + rhs.im * rhs.im;
+        let num = self * c(rhs.re, -rhs.im);
+        c(num.re / norm_sqr, num.im / norm_sqr)
+    }
+}
+
+impl std::ops::Rem for Complex {
+    type Output = Complex;
+    fn rem(self, _: Complex) -> Complex {
+        c(0.0, 0.0)
+    }
+}
+
+impl std::ops::DivAssign for Complex {
+    fn div_assign(&mut self, rhs: Complex) {
+        *self = *self / rhs;
+    }
+}
+
+impl std::ops::Neg for Complex {
+    type Output = Complex;
+    fn neg(self) -> Complex {
+        c(-self.re, -self.im)
+    }
+}
+
+impl std::iter::Sum for Complex {
+    fn sum<I: Iterator<Item = Complex>>(iter: I) -> Complex {
+        iter.fold(c(0.0, 0.0), |a, b| a + b)
+    }
+}
+
+impl num_traits::Zero for Complex {
+    fn zero() -> Complex {
+        c(0.0, 0.0)
+    }
+    fn is_zero(&self) -> bool {
+        self.re == 0.0 && self.im == 0.0
+    }
+}
+
+impl num_traits::One for Complex {
+    fn one() -> Complex {
+        c(1.0, 0.0)
+    }
+}
+
+impl num_traits::Num for Complex {
+    type FromStrRadixErr = num_traits::ParseFloatError;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Complex, Self::FromStrRadixErr> {
+        f64::from_str_radix(s, radix).map(|re| c(re, 0.0))
+    }
+}
+
+impl PivotMagnitude for Complex {
+    type Magnitude = f64;
+    fn pivot_magnitude(&self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+#[test]
+fn complex_inverse() -> Result<(), DimensionError> {
+    // the first column needs pivoting, and only its imaginary parts are nonzero
+    let mat = matrix! {{c(0.0, 1.0), c(2.0, 0.0)}, {c(0.0, 3.0), c(1.0, -1.0)}};
+    let inv = mat.clone().inv()?.unwrap();
+    let prod = (mat.clone() * inv)?;
+    let id: Matrix<Complex> = Matrix::one(2)?;
+    for i in 0..2 {
+        for j in 0..2 {
+            assert!((prod[i][j] - id[i][j]).pivot_magnitude() < 1e-24);
+        }
+    }
+    // det = i·(1 - i) - 2·3i = 1 - 5i
+    let det = mat.det()?;
+    assert!((det - c(1.0, -5.0)).pivot_magnitude() < 1e-24);
+
+    let singular = matrix! {{c(1.0, 1.0), c(2.0, 2.0)}, {c(1.0, 0.0), c(2.0, 0.0)}};
+    assert_eq!(singular.clone().inv()?, None);
+    assert_eq!(singular.det()?, c(0.0, 0.0));
     Ok(())
 }
+
+impl Conjugate for Complex {
+    type Real = f64;
+    fn conjugate(&self) -> Complex {
+        c(self.re, -self.im)
+    }
+    fn modulus(&self) -> f64 {
+        self.pivot_magnitude().sqrt()
+    }
+}
+
+#[test]
+fn hermitian_and_unitary() {
+    let zero = c(0.0, 0.0);
+    let pauli_y = matrix! {{zero, c(0.0, -1.0)}, {c(0.0, 1.0), zero}};
+    assert_eq!(pauli_y.conjugate_transpose(), pauli_y);
+    assert!(pauli_y.is_hermitian(1e-12));
+    assert!(pauli_y.is_unitary(1e-12));
+    // symmetric, but not hermitian
+    let sym = matrix! {{zero, c(0.0, 1.0)}, {c(0.0, 1.0), zero}};
+    assert!(!sym.is_hermitian(1e-12));
+    assert!(sym.is_unitary(1e-12));
+
+    let h = std::f64::consts::FRAC_1_SQRT_2;
+    let phase = matrix! {{c(h, 0.0), c(0.0, h)}, {c(0.0, h), c(h, 0.0)}};
+    assert!(phase.is_unitary(1e-12));
+    assert!(!phase.is_hermitian(1e-12));
+    let not_unitary = matrix! {{c(1.0, 1.0), zero}, {zero, c(1.0, 0.0)}};
+    assert!(!not_unitary.is_unitary(1e-12));
+}
@@ -0,0 +1,244 @@
+mod common;
+
+use common::{assert_mat_eq, random};
+use libmat::{
+    err::DimensionError,
+    mat::{Matrix, Vector},
+    matrix, vector,
+};
+
+#[test]
+fn qr_square() -> Result<(), DimensionError> {
+    let a = matrix! {{12.0, -51.0, 4.0}, {6.0, 167.0, -68.0}, {-4.0, 24.0, -41.0}};
+    let (q, r) = a.qr_decompose()?.unwrap();
+    assert_mat_eq(&(q.clone() * r.clone())?, &a, 1e-10);
+    assert_mat_eq(&(q.transpose() * q)?, &Matrix::one(3)?, 1e-10);
+    for i in 0..3 {
+        for j in 0..i {
+            assert_eq!(r[i][j], 0.0);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn qr_rectangular() -> Result<(), DimensionError> {
+    let a = matrix! {{1.0, 2.0, 3.0}, {4.0, 5.0, 6.0}, {7.0, 8.0, 10.0}, {1.0, 0.0, 1.0}};
+    let (q, r) = a.qr_decompose()?.unwrap();
+    assert_eq!(q.dims(), Matrix::<f64>::one(4)?.dims());
+    assert_eq!(r.dims(), a.dims());
+    assert_mat_eq(&(q.clone() * r)?, &a, 1e-10);
+    assert_mat_eq(&(q.transpose() * q)?, &Matrix::one(4)?, 1e-10);
+
+    let b = a.transpose();
+    let (q, r) = b.qr_decompose()?.unwrap();
+    assert_mat_eq(&(q * r)?, &b, 1e-10);
+    Ok(())
+}
+
+#[test]
+fn qr_rank_deficient() -> Result<(), DimensionError> {
+    let a = matrix! {{1.0, 2.0, 3.0}, {2.0, 4.0, 6.0}, {1.0, 0.0, 1.0}};
+    assert_eq!(a.qr_decompose()?, None);
+    let z: Matrix<f64> = Matrix::zero(3, 2)?;
+    assert_eq!(z.qr_decompose()?, None);
+    Ok(())
+}
+
+#[test]
+fn cholesky() -> Result<(), DimensionError> {
+    let a = matrix! {{25.0, 15.0, -5.0}, {15.0, 18.0, 0.0}, {-5.0, 0.0, 11.0}};
+    let l = a.cholesky()?.unwrap();
+    assert_eq!(
+        l,
+        matrix! {{5.0, 0.0, 0.0}, {3.0, 3.0, 0.0}, {-1.0, 1.0, 3.0}}
+    );
+    assert_mat_eq(&(l.clone() * l.transpose())?, &a, 1e-12);
+    Ok(())
+}
+
+#[test]
+fn cholesky_invalid() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = Matrix::zero(2, 3)?;
+    assert_eq!(a.cholesky(), Err(DimensionError::NoSquare));
+    let b = matrix! {{1.0, 0.0}, {0.0, 0.0}};
+    assert_eq!(b.cholesky()?, None);
+    let c = matrix! {{-1.0, 0.0}, {0.0, 1.0}};
+    assert_eq!(c.cholesky()?, None);
+    Ok(())
+}
+
+fn assert_svd(a: &Matrix<f64>) -> Result<(), DimensionError> {
+    let k = a.rows().min(a.cols());
+    let (u, s, vt) = a.svd()?;
+    assert_eq!((u.rows(), u.cols()), (a.rows(), k));
+    assert_eq!((vt.rows(), vt.cols()), (k, a.cols()));
+    assert!(s.windows(2).all(|w| w[0] >= w[1]));
+    assert_mat_eq(&(u.transpose() * u.clone())?, &Matrix::one(k)?, 1e-10);
+    assert_mat_eq(&(vt.clone() * vt.transpose())?, &Matrix::one(k)?, 1e-10);
+    assert_mat_eq(&((u * Matrix::diag_with(k, &s)?)? * vt)?, a, 1e-10);
+    Ok(())
+}
+
+#[test]
+fn svd_shapes() -> Result<(), DimensionError> {
+    let a = matrix! {{1.0, 2.0, 3.0}, {4.0, 5.0, 6.0}, {7.0, 8.0, 10.0}, {1.0, 0.0, 1.0}};
+    assert_svd(&a)?;
+    assert_svd(&a.transpose())?;
+    assert_svd(&matrix! {{2.0, -1.0}, {-1.0, 2.0}})?;
+    assert_svd(&Matrix::new(1, 4, 2.0)?)?;
+    Ok(())
+}
+
+#[test]
+fn svd_rank_deficient() -> Result<(), DimensionError> {
+    let a = matrix! {{1.0, 2.0, 3.0}, {2.0, 4.0, 6.0}, {1.0, 0.0, 1.0}};
+    assert_svd(&a)?;
+    let (_, s, _) = a.svd()?;
+    assert!(s[2].abs() < 1e-10);
+
+    let z: Matrix<f64> = Matrix::zero(3, 2)?;
+    assert_svd(&z)?;
+    assert_eq!(z.svd()?.1, vec![0.0, 0.0]);
+    Ok(())
+}
+
+#[test]
+fn svd_non_finite() {
+    let a = matrix! {{1.0, f64::NAN}, {0.0, 1.0}};
+    assert_eq!(a.svd().err(), Some(DimensionError::NoConvergence(100)));
+    assert!(a.svd_with_max_iter(5).unwrap().is_none());
+    assert!(a.two_norm().is_nan());
+}
+
+#[test]
+fn svd_larger() -> Result<(), DimensionError> {
+    let a = random(120, 30, 42);
+    assert_svd(&a)?;
+    assert_svd(&a.transpose())?;
+    Ok(())
+}
+
+#[test]
+fn rank_with_tol() -> Result<(), DimensionError> {
+    let a =
+        matrix! {{1.0, 2.0, 0.0, 1.0, 3.0}, {2.0, 4.0, 1.0, 0.0, 1.0}, {3.0, 6.0, 1.0, 1.0, 4.0}};
+    assert_eq!(a.rank_with_tol(1e-10)?, 2);
+    assert_eq!(a.transpose().rank_with_tol(1e-10)?, 2);
+    assert_eq!(Matrix::<f64>::one(4)?.rank_with_tol(1e-10)?, 4);
+    assert_eq!(Matrix::<f64>::zero(2, 3)?.rank_with_tol(1e-10)?, 0);
+    let b = matrix! {{1.0, 0.0}, {0.0, 1e-6}};
+    assert_eq!(b.rank_with_tol(1e-10)?, 2);
+    assert_eq!(b.rank_with_tol(1e-3)?, 1);
+    Ok(())
+}
+
+#[test]
+fn thin_qr() -> Result<(), DimensionError> {
+    let a = matrix! {{1.0, -1.0, 4.0}, {1.0, 4.0, -2.0}, {1.0, 4.0, 2.0}, {1.0, -1.0, 0.0}};
+    let (q, r) = a.qr()?;
+    assert_eq!((q.rows(), q.cols()), (4, 3));
+    assert_eq!((r.rows(), r.cols()), (3, 3));
+    assert_mat_eq(&(q.transpose() * q.clone())?, &Matrix::one(3)?, 1e-12);
+    assert_mat_eq(&(q * r.clone())?, &a, 1e-12);
+    for i in 0..3 {
+        for j in 0..i {
+            assert_eq!(r[i][j], 0.0);
+        }
+    }
+
+    let wide = a.transpose();
+    let (q, r) = wide.qr()?;
+    assert_eq!((q.rows(), q.cols()), (3, 3));
+    assert_eq!((r.rows(), r.cols()), (3, 4));
+    assert_mat_eq(&(q * r)?, &wide, 1e-12);
+
+    let singular = matrix! {{1.0, 2.0}, {2.0, 4.0}, {3.0, 6.0}};
+    let (q, r) = singular.qr()?;
+    assert_mat_eq(&(q * r)?, &singular, 1e-12);
+    Ok(())
+}
+
+#[test]
+fn cholesky_solve() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{4.0, 12.0, -16.0}, {12.0, 37.0, -43.0}, {-16.0, -43.0, 98.0}};
+    let b = vector![-12.0, -31.0, 82.0];
+    let x = a.cholesky_solve(&b)?.unwrap();
+    let expected = a.solve(&b)?.unwrap();
+    for (x, y) in x.iter().zip(expected.iter()) {
+        assert!((x - y).abs() < 1e-10);
+    }
+    for (x, y) in x.iter().zip([1.0_f64, 0.0, 1.0].iter()) {
+        assert!((x - y).abs() < 1e-10);
+    }
+
+    let indefinite = matrix! {{1.0, 2.0}, {2.0, 1.0}};
+    assert_eq!(indefinite.cholesky_solve(&vector![1.0, 1.0])?, None);
+    assert!(a.cholesky_solve(&vector![1.0, 1.0]).is_err());
+    Ok(())
+}
+
+#[test]
+fn ldl() -> Result<(), DimensionError> {
+    // symmetric indefinite, the kind of matrix found in saddle point systems
+    let a: Matrix<f64> = matrix! {
+        {4.0, 1.0, 2.0, 1.0},
+        {1.0, -3.0, 0.5, 2.0},
+        {2.0, 0.5, 3.0, 0.0},
+        {1.0, 2.0, 0.0, -2.0}
+    };
+    let (l, d) = a.ldl()?.unwrap();
+    assert!(l.is_lower_triangular());
+    assert_eq!(l.diagonal(), vec![1.0; 4]);
+    assert!(d.iter().any(|x| *x < 0.0) && d.iter().any(|x| *x > 0.0));
+    let ld = (l.clone() * Matrix::from_diag(&d)?)?;
+    assert_mat_eq(&(ld * l.transpose())?, &a, 1e-12);
+
+    // for positive-definite matrices D holds the squared diagonal of the Cholesky factor
+    let spd: Matrix<f64> = matrix! {{4.0, 12.0, -16.0}, {12.0, 37.0, -43.0}, {-16.0, -43.0, 98.0}};
+    let (_, d) = spd.ldl()?.unwrap();
+    let c = spd.cholesky()?.unwrap();
+    for (i, d_i) in d.iter().enumerate() {
+        assert!((d_i - c[i][i] * c[i][i]).abs() < 1e-12);
+    }
+
+    let singular: Matrix<f64> = matrix! {{1.0, 2.0, 3.0}, {2.0, 4.0, 6.0}, {3.0, 6.0, 10.0}};
+    assert_eq!(singular.ldl()?, None);
+    let b: Matrix<f64> = Matrix::zero(2, 3)?;
+    assert_eq!(b.ldl(), Err(DimensionError::NoSquare));
+    Ok(())
+}
+
+#[test]
+fn hessenberg() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = Matrix::from_fn(5, 5, |i, j| ((3 * i + 7 * j) % 11) as f64 - 4.0)?;
+    let (h, q) = a.hessenberg()?;
+    for i in 2..5 {
+        for j in 0..(i - 1) {
+            assert_eq!(h[i][j], 0.0);
+        }
+    }
+    assert!(q.is_orthogonal(1e-12));
+    assert_mat_eq(&((q.clone() * h.clone())? * q.transpose())?, &a, 1e-10);
+    // similar matrices have the same trace
+    assert!((h.trace()? - a.trace()?).abs() < 1e-10);
+
+    // symmetric matrices become tridiagonal
+    let s: Matrix<f64> = matrix! {{4.0, 1.0, -2.0, 2.0}, {1.0, 2.0, 0.0, 1.0}, {-2.0, 0.0, 3.0, -2.0}, {2.0, 1.0, -2.0, -1.0}};
+    let (t, q) = s.hessenberg()?;
+    for i in 0..4 {
+        for j in (i + 2)..4 {
+            assert!(t[i][j].abs() < 1e-12);
+        }
+    }
+    assert_mat_eq(&((q.clone() * t)? * q.transpose())?, &s, 1e-10);
+
+    // already upper Hessenberg and tiny matrices are left alone
+    let u: Matrix<f64> = matrix! {{1.0, 2.0}, {3.0, 4.0}};
+    assert_eq!(u.hessenberg()?, (u.clone(), Matrix::one(2)?));
+    let one: Matrix<f64> = matrix! {{5.0}};
+    assert_eq!(one.hessenberg()?, (one.clone(), Matrix::one(1)?));
+    let b: Matrix<f64> = Matrix::zero(2, 3)?;
+    assert_eq!(b.hessenberg(), Err(DimensionError::NoSquare));
+    Ok(())
+}
@@ -26,7 +26,7 @@ fn null_mat() -> Result<(), DimensionError> {
 #[test]
 fn some_dets() -> Result<(), DimensionError> {
     let a = Matrix::<f32>::from_vec(3, 3, vec![1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 2.0, 1.0, 3.0])?;
-    assert_eq!(a.det()?, -12_f32);
+    assert!((a.det()? + 12_f32).abs() < 1e-5);
     let b = Matrix::<f32>::from_vec(
         8,
         8,
@@ -39,6 +39,39 @@ fn some_dets() -> Result<(), DimensionError> {
         .map(|x| (*x as i16).into())
         .collect(),
     )?;
-    assert_eq!(b.det()?, -15546220_f32);
+    assert!((b.det()? / -15546220_f32 - 1.0).abs() < 1e-5);
+    Ok(())
+}
+
+#[test]
+fn small_dets() -> Result<(), DimensionError> {
+    let a = Matrix::from_vec(1, 1, vec![-3.0_f64])?;
+    assert_eq!(a.det()?, -3.0);
+    let b = Matrix::from_vec(2, 2, vec![3.0_f64, 8.0, 4.0, 6.0])?;
+    assert!((b.det()? + 14.0).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn permutation_sign() -> Result<(), DimensionError> {
+    let a = Matrix::from_vec(2, 2, vec![0.0_f64, 1.0, 1.0, 0.0])?;
+    assert_eq!(a.det()?, -1.0);
+    let b = Matrix::from_vec(
+        4,
+        4,
+        vec![
+            1.0_f64, 0.0, 2.0, -1.0, 3.0, 0.0, 0.0, 5.0, 2.0, 1.0, 4.0, -3.0, 1.0, 0.0, 5.0, 0.0,
+        ],
+    )?;
+    assert!((b.det()? - 30.0).abs() < 1e-10);
+    Ok(())
+}
+
+#[test]
+fn singular_mat() -> Result<(), DimensionError> {
+    let a = Matrix::from_vec(2, 2, vec![1.0_f64, 2.0, 2.0, 4.0])?;
+    assert!(a.det()?.abs() < 1e-12);
+    let b = Matrix::from_vec(3, 3, vec![1.0_f64, 2.0, 3.0, 4.0, 5.0, 6.0, 0.0, 0.0, 0.0])?;
+    assert!(b.det()?.abs() < 1e-12);
     Ok(())
 }
@@ -0,0 +1,160 @@
+use libmat::{err::DimensionError, mat::Matrix, matrix};
+
+fn assert_eigenpairs(a: &Matrix<f64>, values: &[f64], vectors: &Matrix<f64>, eps: f64) {
+    for (j, value) in values.iter().enumerate() {
+        let norm: f64 = (0..a.rows()).map(|i| vectors[i][j].powi(2)).sum();
+        assert!((norm - 1.0).abs() < eps);
+        for i in 0..a.rows() {
+            let av: f64 = (0..a.cols()).map(|k| a[i][k] * vectors[k][j]).sum();
+            assert!(
+                (av - value * vectors[i][j]).abs() < eps,
+                "A v != λ v for eigenvalue {}",
+                value
+            );
+        }
+    }
+}
+
+#[test]
+fn eig_symmetric() -> Result<(), DimensionError> {
+    let a = matrix! {{4.0, 1.0, 2.0}, {1.0, 3.0, 0.0}, {2.0, 0.0, 5.0}};
+    let (values, vectors) = a.eig()?;
+    assert!(values.windows(2).all(|w| w[0] >= w[1]));
+    assert!((values.iter().sum::<f64>() - 12.0).abs() < 1e-10);
+    assert_eigenpairs(&a, &values, &vectors, 1e-10);
+    Ok(())
+}
+
+#[test]
+fn eig_general() -> Result<(), DimensionError> {
+    let a = matrix! {{2.0, 1.0, 0.0}, {1.0, 3.0, 1.0}, {0.0, 0.0, 4.0}};
+    let (values, vectors) = a.eig()?;
+    assert_eigenpairs(&a, &values, &vectors, 1e-8);
+
+    // similar to diag(4, -2, 1, 3)
+    let b = matrix! {
+        {10.0, -12.0, 12.0, -12.0},
+        {9.0, -14.0, 15.0, -15.0},
+        {1.0, -2.0, 1.0, 2.0},
+        {-2.0, 4.0, -6.0, 9.0}
+    };
+    let (values, vectors) = b.eig()?;
+    for (value, expected) in values.iter().zip([4.0_f64, 3.0, 1.0, -2.0].iter()) {
+        assert!((value - expected).abs() < 1e-8);
+    }
+    assert_eigenpairs(&b, &values, &vectors, 1e-8);
+
+    let c: Matrix<f64> = matrix! {{1.0, 1.0}, {0.0, 1.0}};
+    // defective, so the computed eigenvalues are only accurate to about the square root of eps
+    let (values, _) = c.eig()?;
+    assert!(values.iter().all(|value| (value - 1.0).abs() < 1e-6));
+    Ok(())
+}
+
+#[test]
+fn eig_invalid() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = Matrix::zero(2, 3)?;
+    assert_eq!(a.eig(), Err(DimensionError::NoSquare));
+    let b = matrix! {{1.0, -2.0, 0.0}, {2.0, 1.0, 0.0}, {0.0, 0.0, 3.0}};
+    assert_eq!(b.eig(), Err(DimensionError::ComplexEigenvalues));
+    // dense, not in Hessenberg form, one real eigenvalue and a complex pair
+    let c = matrix! {{1.0, 1.0, 0.0}, {0.0, 2.0, 1.0}, {5.0, 0.0, 3.0}};
+    assert_eq!(c.eig(), Err(DimensionError::ComplexEigenvalues));
+    Ok(())
+}
+
+#[test]
+fn eig_sym() -> Result<(), DimensionError> {
+    let a = matrix! {{4.0, -2.0, 1.0, 0.5}, {-2.0, 6.0, 0.0, 1.0}, {1.0, 0.0, 2.0, -1.0}, {0.5, 1.0, -1.0, 3.0}};
+    let (values, vectors) = a.eig_sym()?;
+    assert!(values.windows(2).all(|w| w[0] >= w[1]));
+    assert_eigenpairs(&a, &values, &vectors, 1e-10);
+    // eigenvectors are orthonormal
+    let vtv: Matrix<f64> = (vectors.transpose() * vectors.clone())?;
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((vtv[i][j] - expected).abs() < 1e-10);
+        }
+    }
+
+    let d: Matrix<f64> = Matrix::diag_with(3, &[2.0, -1.0, 5.0])?;
+    let (values, _) = d.eig_sym()?;
+    assert_eq!(values, vec![5.0, 2.0, -1.0]);
+
+    let nan = matrix! {{1.0, f64::NAN}, {f64::NAN, 1.0}};
+    assert_eq!(nan.eig_sym(), Err(DimensionError::NoConvergence(100)));
+    assert_eq!(nan.eig(), Err(DimensionError::NoConvergence(100)));
+    Ok(())
+}
+
+#[test]
+fn eig_sym_invalid() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = Matrix::zero(2, 3)?;
+    assert_eq!(a.eig_sym(), Err(DimensionError::NoSquare));
+    let b = matrix! {{1.0, 2.0}, {2.1, 1.0}};
+    assert_eq!(b.eig_sym(), Err(DimensionError::NoSymmetric));
+    // asymmetry within tolerance is accepted
+    let c: Matrix<f64> = matrix! {{1.0, 2.0}, {2.0 + 1e-14, 1.0}};
+    let (values, _) = c.eig_sym()?;
+    assert!((values[0] - 3.0).abs() < 1e-10);
+    Ok(())
+}
+
+#[test]
+fn power_iteration() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{4.0, 1.0, 2.0}, {1.0, 3.0, 0.0}, {2.0, 0.0, 5.0}};
+    let (value, vector) = a.power_iteration(10_000, 1e-14)?.unwrap();
+    let (values, _) = a.eig_sym()?;
+    assert!((value - values[0]).abs() < 1e-10);
+    assert_eq!((vector.rows(), vector.cols()), (3, 1));
+    assert_eigenpairs(&a, &[value], &vector, 1e-6);
+
+    // dominant eigenvalue is negative, general matrix similar to diag(-4, 2, -1, -3)
+    let b: Matrix<f64> = matrix! {
+        {-10.0, 12.0, -12.0, 12.0},
+        {-9.0, 14.0, -15.0, 15.0},
+        {-1.0, 2.0, -1.0, -2.0},
+        {2.0, -4.0, 6.0, -9.0}
+    };
+    let (value, vector) = b.power_iteration(10_000, 1e-14)?.unwrap();
+    assert!((value + 4.0).abs() < 1e-8);
+    assert_eigenpairs(&b, &[value], &vector, 1e-6);
+
+    // the zero matrix has the eigenvalue 0 for every vector
+    let z: Matrix<f64> = Matrix::zero(2, 2)?;
+    assert_eq!(z.power_iteration(10, 1e-12)?.unwrap().0, 0.0);
+
+    // two dominant eigenvalues of opposite sign never converge
+    let c = matrix! {{0.0, 1.0}, {1.0, 0.0}};
+    assert_eq!(c.power_iteration(100, 1e-12)?, None);
+    let d: Matrix<f64> = Matrix::zero(2, 3)?;
+    assert_eq!(d.power_iteration(10, 1e-12), Err(DimensionError::NoSquare));
+    Ok(())
+}
+
+#[test]
+fn companion_roots() -> Result<(), DimensionError> {
+    // (x - 1)(x + 2)(x - 3)(x - 4) = x^4 - 6x^3 + 3x^2 + 26x - 24
+    let coeffs = [-24.0, 26.0, 3.0, -6.0];
+    let mat: Matrix<f64> = Matrix::companion(&coeffs)?;
+    assert_eq!(mat.trace()?, 6.0);
+    // det(xI - C) is the polynomial itself
+    for &x in [0.5, -1.0, 2.5].iter() {
+        let shifted = (Matrix::<f64>::one(4)? * x - mat.clone())?;
+        let p = x.powi(4) - 6.0 * x.powi(3) + 3.0 * x * x + 26.0 * x - 24.0;
+        assert!((shifted.det()? - p).abs() < 1e-9);
+    }
+    let (values, vectors) = mat.eig()?;
+    for (value, root) in values.iter().zip([4.0, 3.0, 1.0, -2.0].iter()) {
+        assert!((value - root).abs() < 1e-8);
+    }
+    assert_eigenpairs(&mat, &values, &vectors, 1e-8);
+
+    assert_eq!(Matrix::companion(&[5]), Ok(matrix! {{-5}}));
+    assert_eq!(
+        Matrix::<f64>::companion(&[]),
+        Err(DimensionError::InvalidDimensions)
+    );
+    Ok(())
+}
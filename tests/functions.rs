@@ -0,0 +1,66 @@
+mod common;
+
+use common::assert_mat_rel_eq;
+use libmat::{err::DimensionError, mat::Matrix, matrix};
+
+#[test]
+fn expm_zero_and_diagonal() -> Result<(), DimensionError> {
+    let zero: Matrix<f64> = Matrix::zero(3, 3)?;
+    assert_eq!(zero.expm()?, Matrix::one(3)?);
+
+    let diag: Matrix<f64> = Matrix::diag_with(3, &[1.0, -2.0, 10.0])?;
+    let expected = Matrix::diag_with(3, &[1f64.exp(), (-2f64).exp(), 10f64.exp()])?;
+    assert_mat_rel_eq(&diag.expm()?, &expected, 1e-12);
+    Ok(())
+}
+
+#[test]
+fn expm_general() -> Result<(), DimensionError> {
+    // nilpotent: e^N = I + N + N²/2
+    let n: Matrix<f64> = matrix! {{0.0, 1.0, 2.0}, {0.0, 0.0, 3.0}, {0.0, 0.0, 0.0}};
+    let expected = matrix! {{1.0, 1.0, 3.5}, {0.0, 1.0, 3.0}, {0.0, 0.0, 1.0}};
+    assert_mat_rel_eq(&n.expm()?, &expected, 1e-12);
+
+    // A = P D P⁻¹ with D = diag(1, 2) and P = {{1, 1}, {0, 1}}
+    let a: Matrix<f64> = matrix! {{1.0, 1.0}, {0.0, 2.0}};
+    let (e1, e2) = (1f64.exp(), 2f64.exp());
+    let expected = matrix! {{e1, e2 - e1}, {0.0, e2}};
+    assert_mat_rel_eq(&a.expm()?, &expected, 1e-12);
+
+    // e^A e^-A = I
+    let b: Matrix<f64> = matrix! {{0.5, -3.0, 1.0}, {2.0, 1.0, 0.0}, {-1.0, 4.0, -2.0}};
+    let prod = (b.expm()? * (-b).expm()?)?;
+    assert_mat_rel_eq(&prod, &Matrix::one(3)?, 1e-10);
+    Ok(())
+}
+
+#[test]
+fn expm_invalid() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = Matrix::zero(2, 3)?;
+    assert_eq!(a.expm(), Err(DimensionError::NoSquare));
+    Ok(())
+}
+
+#[test]
+fn expm_large_norm() -> Result<(), DimensionError> {
+    // rotation generator scaled by 20: e^A is the rotation by 20 radians
+    let t = 20.0_f64;
+    let a: Matrix<f64> = matrix! {{0.0, -t}, {t, 0.0}};
+    let expected = matrix! {{t.cos(), -t.sin()}, {t.sin(), t.cos()}};
+    assert_mat_rel_eq(&a.expm()?, &expected, 1e-10);
+
+    // exercises every Padé degree
+    for scale in [1e-3, 0.1, 0.5, 1.5, 4.0, 30.0] {
+        let d: Matrix<f64> = Matrix::diag_with(2, &[scale, -scale])?;
+        let expected = Matrix::diag_with(2, &[scale.exp(), (-scale).exp()])?;
+        assert_mat_rel_eq(&d.expm()?, &expected, 1e-12);
+    }
+    Ok(())
+}
+
+#[test]
+fn expm_non_finite() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{f64::NAN, 0.0}, {0.0, 1.0}};
+    assert!(a.expm()?.iter().all(|x| x.is_nan()));
+    Ok(())
+}
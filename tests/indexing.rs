@@ -16,3 +16,299 @@ fn double_idx() -> Result<(), DimensionError> {
     assert_eq!(a[1][1], 1);
     Ok(())
 }
+
+#[test]
+fn submatrix() -> Result<(), DimensionError> {
+    let a = Matrix::from_vec(3, 4, (1..=12).collect())?;
+    assert_eq!(a.submatrix(.., ..)?, a);
+    assert_eq!(a.submatrix(0..3, 0..=3)?, a);
+    assert_eq!(
+        a.submatrix(1..3, 1..3)?,
+        Matrix::from_vec(2, 2, vec![6, 7, 10, 11])?
+    );
+    assert_eq!(a.submatrix(1..=2, 1..=2)?, a.submatrix(1..3, 1..3)?);
+    assert_eq!(
+        a.submatrix(0..1, ..)?,
+        Matrix::from_vec(1, 4, vec![1, 2, 3, 4])?
+    );
+    assert_eq!(
+        a.submatrix(.., 3..)?,
+        Matrix::from_vec(3, 1, vec![4, 8, 12])?
+    );
+    // drop the last row and column of a homogeneous transform
+    assert_eq!(
+        a.submatrix(..a.rows() - 1, ..a.cols() - 1)?,
+        Matrix::from_vec(2, 3, vec![1, 2, 3, 5, 6, 7])?
+    );
+    assert_eq!(
+        a.submatrix(2..4, 0..1),
+        Err(DimensionError::OutOfBounds(3, 3))
+    );
+    assert_eq!(
+        a.submatrix(0..1, 0..=4),
+        Err(DimensionError::OutOfBounds(4, 4))
+    );
+    assert_eq!(
+        a.submatrix(2..2, 0..1),
+        Err(DimensionError::InvalidDimensions)
+    );
+    Ok(())
+}
+
+#[test]
+fn set_submatrix() -> Result<(), DimensionError> {
+    let mut a: Matrix<i32> = Matrix::zero(3, 4)?;
+    let block = Matrix::from_vec(2, 2, vec![1, 2, 3, 4])?;
+    a.set_submatrix(1, 2, &block)?;
+    assert_eq!(a.submatrix(1.., 2..)?, block);
+    assert_eq!(
+        a,
+        Matrix::from_vec(3, 4, vec![0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4])?
+    );
+    let copy = a.clone();
+    assert_eq!(
+        a.set_submatrix(2, 0, &block),
+        Err(DimensionError::OutOfBounds(3, 3))
+    );
+    assert_eq!(
+        a.set_submatrix(0, 3, &block),
+        Err(DimensionError::OutOfBounds(4, 4))
+    );
+    assert_eq!(a, copy);
+    Ok(())
+}
+
+#[test]
+fn delete_row_col() -> Result<(), DimensionError> {
+    let a = Matrix::from_vec(3, 4, (1..=12).collect())?;
+    assert_eq!(
+        a.delete_row(0)?,
+        Matrix::from_vec(2, 4, (5..=12).collect())?
+    );
+    assert_eq!(a.delete_row(2)?, Matrix::from_vec(2, 4, (1..=8).collect())?);
+    assert_eq!(
+        a.delete_col(3)?,
+        Matrix::from_vec(3, 3, vec![1, 2, 3, 5, 6, 7, 9, 10, 11])?
+    );
+    assert_eq!(
+        a.delete_row(1)?.delete_col(1)?,
+        a.delete_col(1)?.delete_row(1)?
+    );
+    assert_eq!(a.delete_row(3), Err(DimensionError::OutOfBounds(3, 3)));
+    assert_eq!(a.delete_col(4), Err(DimensionError::OutOfBounds(4, 4)));
+    let row = Matrix::from_vec(1, 2, vec![1, 2])?;
+    assert_eq!(row.delete_row(0), Err(DimensionError::InvalidDimensions));
+    assert_eq!(row.delete_col(0)?, Matrix::from_vec(1, 1, vec![2])?);
+    Ok(())
+}
+
+#[test]
+fn insert_row_col() -> Result<(), DimensionError> {
+    let a = Matrix::from_vec(3, 4, (1..=12).collect())?;
+    for i in 0..3 {
+        assert_eq!(a.delete_row(i)?.insert_row(i, a[i].to_vec())?, a);
+    }
+    for j in 0..4 {
+        let col = (0..3).map(|i| a[i][j]).collect();
+        assert_eq!(a.delete_col(j)?.insert_col(j, col)?, a);
+    }
+    assert_eq!(
+        a.insert_row(0, vec![0; 4])?,
+        Matrix::from_vec(4, 4, [vec![0; 4], (1..=12).collect()].concat())?
+    );
+    assert_eq!(
+        a.insert_col(4, vec![0; 3])?,
+        Matrix::from_vec(3, 5, vec![1, 2, 3, 4, 0, 5, 6, 7, 8, 0, 9, 10, 11, 12, 0])?
+    );
+    assert_eq!(
+        a.insert_row(4, vec![0; 4]),
+        Err(DimensionError::OutOfBounds(4, 3))
+    );
+    assert_eq!(
+        a.insert_row(0, vec![0; 3]),
+        Err(DimensionError::InvalidInputDimensions(3, 4))
+    );
+    assert_eq!(
+        a.insert_col(5, vec![0; 3]),
+        Err(DimensionError::OutOfBounds(5, 4))
+    );
+    assert_eq!(
+        a.insert_col(0, vec![0; 4]),
+        Err(DimensionError::InvalidInputDimensions(4, 3))
+    );
+    Ok(())
+}
+
+#[test]
+fn insert_row_col_mut() -> Result<(), DimensionError> {
+    let original = Matrix::from_vec(3, 4, (1..=12).collect())?;
+    let mut a = original.clone();
+    a.insert_row_mut(0, &[0; 4])?;
+    assert_eq!(
+        a,
+        Matrix::from_vec(4, 4, [vec![0; 4], (1..=12).collect()].concat())?
+    );
+    a.insert_row_mut(4, &[13, 14, 15, 16])?;
+    a.insert_row_mut(2, &[-1; 4])?;
+    assert_eq!((a.rows(), a.cols()), (6, 4));
+    assert_eq!(a[2], [-1; 4]);
+    assert_eq!(a[5], [13, 14, 15, 16]);
+
+    let mut b = original.clone();
+    b.insert_col_mut(4, &[0; 3])?;
+    assert_eq!(
+        b,
+        Matrix::from_vec(3, 5, vec![1, 2, 3, 4, 0, 5, 6, 7, 8, 0, 9, 10, 11, 12, 0])?
+    );
+    b.insert_col_mut(0, &[-1; 3])?;
+    b.insert_col_mut(3, &[7, 8, 9])?;
+    assert_eq!(
+        b,
+        Matrix::from_vec(
+            3,
+            7,
+            vec![-1, 1, 2, 7, 3, 4, 0, -1, 5, 6, 8, 7, 8, 0, -1, 9, 10, 9, 11, 12, 0]
+        )?
+    );
+
+    assert_eq!(
+        a.insert_row_mut(7, &[0; 4]),
+        Err(DimensionError::OutOfBounds(7, 6))
+    );
+    assert_eq!(
+        a.insert_row_mut(0, &[0; 3]),
+        Err(DimensionError::InvalidInputDimensions(3, 4))
+    );
+    assert_eq!(
+        b.insert_col_mut(8, &[0; 3]),
+        Err(DimensionError::OutOfBounds(8, 7))
+    );
+    assert_eq!(
+        b.insert_col_mut(0, &[0; 4]),
+        Err(DimensionError::InvalidInputDimensions(4, 3))
+    );
+    Ok(())
+}
+
+#[test]
+fn remove_row_col() -> Result<(), DimensionError> {
+    let original = Matrix::from_vec(3, 4, (1..=12).collect::<Vec<i32>>())?;
+    for i in 0..3 {
+        let mut a = original.clone();
+        let row = a.remove_row(i)?;
+        assert_eq!(row, original[i].to_vec());
+        assert_eq!(a, original.delete_row(i)?);
+        a.insert_row_mut(i, &row)?;
+        assert_eq!(a, original);
+    }
+    for j in 0..4 {
+        let mut a = original.clone();
+        let col = a.remove_col(j)?;
+        assert_eq!(col, (0..3).map(|i| original[i][j]).collect::<Vec<_>>());
+        assert_eq!(a, original.delete_col(j)?);
+        a.insert_col_mut(j, &col)?;
+        assert_eq!(a, original);
+    }
+    let mut a = original.clone();
+    assert_eq!(a.remove_row(3), Err(DimensionError::OutOfBounds(3, 3)));
+    assert_eq!(a.remove_col(4), Err(DimensionError::OutOfBounds(4, 4)));
+    a.remove_row(0)?;
+    a.remove_row(0)?;
+    assert_eq!(a.remove_row(0), Err(DimensionError::InvalidDimensions));
+    let mut col = Matrix::from_vec(2, 1, vec![1, 2])?;
+    assert_eq!(col.remove_col(0), Err(DimensionError::InvalidDimensions));
+    assert_eq!(a, Matrix::from_vec(1, 4, vec![9, 10, 11, 12])?);
+    Ok(())
+}
+
+#[test]
+fn tuple_idx() -> Result<(), DimensionError> {
+    let mut a = Matrix::from_vec(2, 3, (1..=6).collect::<Vec<u32>>())?;
+    for i in 0..2 {
+        for j in 0..3 {
+            assert_eq!(a[(i, j)], a[i][j]);
+        }
+    }
+    a[(0, 2)] = 10;
+    assert_eq!(a[0][2], 10);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn tuple_idx_out_of_bounds() {
+    let a = Matrix::<u32>::one(2).unwrap();
+    let _ = a[(0, 2)];
+}
+
+#[test]
+fn get() -> Result<(), DimensionError> {
+    let mut a = Matrix::from_vec(2, 3, (1..=6).collect::<Vec<u32>>())?;
+    assert_eq!(a.get(0, 0), Some(&1));
+    assert_eq!(a.get(1, 2), Some(&6));
+    // (0, 3) would be a valid offset into the storage but is out of bounds
+    assert_eq!(a.get(0, 3), None);
+    assert_eq!(a.get(2, 0), None);
+    if let Some(x) = a.get_mut(1, 1) {
+        *x = 0;
+    }
+    assert_eq!(a[1][1], 0);
+    assert_eq!(a.get_mut(5, 5), None);
+    Ok(())
+}
+
+#[test]
+fn reshape() -> Result<(), DimensionError> {
+    let a = Matrix::from_vec(2, 3, (1..=6).collect::<Vec<u32>>())?;
+    assert_eq!(a.reshape(2, 3)?, a);
+    let row = a.reshape(1, 6)?;
+    assert_eq!(row, Matrix::from_vec(1, 6, (1..=6).collect())?);
+    assert_eq!(row.reshape(6, 1)?, row.transpose());
+    assert_eq!(a.reshape(3, 2)?[2], [5, 6]);
+    assert_eq!(row.into_reshaped(2, 3)?, a);
+    assert_eq!(
+        a.reshape(4, 2),
+        Err(DimensionError::InvalidInputDimensions(6, 8))
+    );
+    assert_eq!(
+        a.reshape(0, 6),
+        Err(DimensionError::InvalidInputDimensions(6, 0))
+    );
+    assert_eq!(
+        a.reshape(usize::MAX, 2),
+        Err(DimensionError::InvalidInputDimensions(6, usize::MAX))
+    );
+    Ok(())
+}
+
+#[test]
+fn row_operations() -> Result<(), DimensionError> {
+    let original = Matrix::from_vec(3, 3, (1..=9).collect::<Vec<i32>>())?;
+    let mut a = original.clone();
+    a.swap_rows(2, 0)?;
+    assert_eq!(a, Matrix::from_vec(3, 3, vec![7, 8, 9, 4, 5, 6, 1, 2, 3])?);
+    a.swap_rows(0, 2)?;
+    a.swap_rows(1, 1)?;
+    assert_eq!(a, original);
+
+    a.swap_cols(0, 2)?;
+    assert_eq!(a, Matrix::from_vec(3, 3, vec![3, 2, 1, 6, 5, 4, 9, 8, 7])?);
+    a.swap_cols(2, 0)?;
+    assert_eq!(a, original);
+
+    a.scale_row(0, 3)?;
+    assert_eq!(a[0], [3, 6, 9]);
+    a.add_scaled_row(2, 1, -2)?;
+    assert_eq!(a[2], [-1, -2, -3]);
+    a.add_scaled_row(1, 1, 1)?;
+    assert_eq!(a[1], [8, 10, 12]);
+
+    assert_eq!(a.swap_rows(0, 3), Err(DimensionError::OutOfBounds(3, 3)));
+    assert_eq!(a.swap_cols(4, 0), Err(DimensionError::OutOfBounds(4, 3)));
+    assert_eq!(a.scale_row(3, 2), Err(DimensionError::OutOfBounds(3, 3)));
+    assert_eq!(
+        a.add_scaled_row(0, 5, 1),
+        Err(DimensionError::OutOfBounds(5, 3))
+    );
+    Ok(())
+}
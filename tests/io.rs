@@ -0,0 +1,153 @@
+#![cfg(feature = "io")]
+
+use libmat::{
+    err::{DimensionError, MatrixIoError, ParseMatrixError},
+    mat::{CsvOptions, Matrix},
+    matrix,
+};
+
+#[test]
+fn csv_round_trip() -> Result<(), MatrixIoError> {
+    let mat: Matrix<f64> = matrix! {{1.5, -2.0, 3e-7}, {-0.125, 5.0, 6e12}};
+    let mut csv = Vec::new();
+    mat.to_csv(&mut csv)?;
+    assert_eq!(Matrix::<f64>::from_csv(csv.as_slice())?, mat);
+
+    let ints: Matrix<i32> = Matrix::from_csv(" 1, 2 ,3\r\n-4,5,6\n\n".as_bytes())?;
+    assert_eq!(ints, matrix! {{1, 2, 3}, {-4, 5, 6}});
+    Ok(())
+}
+
+#[test]
+fn csv_invalid() {
+    match Matrix::<f64>::from_csv("1,2\n3,abc\n".as_bytes()) {
+        Err(MatrixIoError::Parse(err)) => {
+            assert_eq!(err, ParseMatrixError::InvalidEntry(1, 1, "abc".to_owned()))
+        }
+        res => panic!("unexpected result {:?}", res),
+    }
+    match Matrix::<i32>::from_csv("1,2\n\n3,4".as_bytes()) {
+        Err(MatrixIoError::Parse(err)) => assert_eq!(
+            err,
+            ParseMatrixError::Dimension(DimensionError::Ragged(2, 0, 2))
+        ),
+        res => panic!("unexpected result {:?}", res),
+    }
+    match Matrix::<i32>::from_csv(&[0xff, 0xfe][..]) {
+        Err(MatrixIoError::Io(_)) => {}
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn csv_delimiter() -> Result<(), MatrixIoError> {
+    let mat: Matrix<f64> = matrix! {{-1.0, 2.5e-3}, {1e20, 0.0}};
+    for delimiter in [',', '\t', ';'].iter() {
+        let options = CsvOptions::new().delimiter(*delimiter);
+        let mut csv = Vec::new();
+        mat.to_csv_with(&mut csv, &options)?;
+        let text = String::from_utf8(csv.clone()).unwrap();
+        assert_eq!(text.lines().next().unwrap().matches(*delimiter).count(), 1);
+        assert_eq!(Matrix::<f64>::from_csv_with(csv.as_slice(), &options)?, mat);
+    }
+    assert_eq!(CsvOptions::default(), CsvOptions::new().delimiter(','));
+
+    // the default delimiter does not split on semicolons
+    match Matrix::<i32>::from_csv("1;2\n3;4\n".as_bytes()) {
+        Err(MatrixIoError::Parse(err)) => {
+            assert_eq!(err, ParseMatrixError::InvalidEntry(0, 0, "1;2".to_owned()))
+        }
+        res => panic!("unexpected result {:?}", res),
+    }
+    Ok(())
+}
+
+fn parse_error<T: std::fmt::Debug>(res: Result<Matrix<T>, MatrixIoError>) -> ParseMatrixError {
+    match res {
+        Err(MatrixIoError::Parse(err)) => err,
+        res => panic!("unexpected result {:?}", res),
+    }
+}
+
+#[test]
+fn matrix_market_array() -> Result<(), MatrixIoError> {
+    let mat: Matrix<f64> = matrix! {{1.5, -2.0, 3e-7}, {-0.125, 5.0, 6e12}};
+    let mut mtx = Vec::new();
+    mat.to_matrix_market(&mut mtx)?;
+    assert_eq!(Matrix::<f64>::from_matrix_market(mtx.as_slice())?, mat);
+    assert!(mtx.starts_with(b"%%MatrixMarket matrix array real general\n"));
+
+    let ints = matrix! {{1, -20}, {300, 0}};
+    let mut mtx = Vec::new();
+    ints.to_matrix_market(&mut mtx)?;
+    assert!(mtx.starts_with(b"%%MatrixMarket matrix array integer general\n"));
+    assert_eq!(Matrix::<i32>::from_matrix_market(mtx.as_slice())?, ints);
+
+    let general =
+        "%%MatrixMarket matrix array integer general\n% comment\n\n2 3\n1\n4\n2\n5\n3\n6\n";
+    let ints: Matrix<i32> = Matrix::from_matrix_market(general.as_bytes())?;
+    assert_eq!(ints, matrix! {{1, 2, 3}, {4, 5, 6}});
+
+    let symmetric = "%%MatrixMarket MATRIX Array Real Symmetric\n3 3\n1 2 3\n4 5\n6\n";
+    let sym: Matrix<f64> = Matrix::from_matrix_market(symmetric.as_bytes())?;
+    assert_eq!(
+        sym,
+        matrix! {{1.0, 2.0, 3.0}, {2.0, 4.0, 5.0}, {3.0, 5.0, 6.0}}
+    );
+    Ok(())
+}
+
+#[test]
+fn matrix_market_coordinate() -> Result<(), MatrixIoError> {
+    let general =
+        "%%MatrixMarket matrix coordinate integer general\n%\n3 4 3\n1 1 7\n3 4 -2\n2 3 5\n";
+    let mat: Matrix<i64> = Matrix::from_matrix_market(general.as_bytes())?;
+    assert_eq!(mat, matrix! {{7, 0, 0, 0}, {0, 0, 5, 0}, {0, 0, 0, -2}});
+
+    let symmetric = "%%MatrixMarket matrix coordinate real symmetric\n2 2 2\n1 1 1.0\n2 1 0.5\n";
+    let mat: Matrix<f64> = Matrix::from_matrix_market(symmetric.as_bytes())?;
+    assert_eq!(mat, matrix! {{1.0, 0.5}, {0.5, 0.0}});
+    Ok(())
+}
+
+#[test]
+fn matrix_market_invalid() {
+    let banner = "%%MatrixMarket matrix coordinate complex general\n1 1 1\n1 1 1 0\n";
+    assert_eq!(
+        parse_error(Matrix::<f64>::from_matrix_market(banner.as_bytes())),
+        ParseMatrixError::InvalidLine(
+            1,
+            "%%MatrixMarket matrix coordinate complex general".to_owned()
+        )
+    );
+    let size = "%%MatrixMarket matrix array real general\n2 2 4\n";
+    assert_eq!(
+        parse_error(Matrix::<f64>::from_matrix_market(size.as_bytes())),
+        ParseMatrixError::InvalidLine(2, "2 2 4".to_owned())
+    );
+    let entry = "%%MatrixMarket matrix coordinate real general\n2 2 1\n2 1 x\n";
+    assert_eq!(
+        parse_error(Matrix::<f64>::from_matrix_market(entry.as_bytes())),
+        ParseMatrixError::InvalidEntry(1, 0, "x".to_owned())
+    );
+    let bounds = "%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n";
+    assert_eq!(
+        parse_error(Matrix::<f64>::from_matrix_market(bounds.as_bytes())),
+        ParseMatrixError::Dimension(DimensionError::OutOfBounds(2, 2))
+    );
+    let count = "%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n";
+    assert_eq!(
+        parse_error(Matrix::<f64>::from_matrix_market(count.as_bytes())),
+        ParseMatrixError::Dimension(DimensionError::InvalidInputDimensions(3, 4))
+    );
+    let nnz = "%%MatrixMarket matrix coordinate real general\n2 2 3\n1 1 1.0\n2 2 2.0\n";
+    assert_eq!(
+        parse_error(Matrix::<f64>::from_matrix_market(nnz.as_bytes())),
+        ParseMatrixError::Dimension(DimensionError::InvalidInputDimensions(2, 3))
+    );
+    let square = "%%MatrixMarket matrix array real symmetric\n2 3\n1\n2\n3\n4\n5\n";
+    assert_eq!(
+        parse_error(Matrix::<f64>::from_matrix_market(square.as_bytes())),
+        ParseMatrixError::Dimension(DimensionError::NoSquare)
+    );
+}
@@ -0,0 +1,144 @@
+use libmat::{err::DimensionError, mat::Matrix, matrix};
+
+#[test]
+fn row_iter() -> Result<(), DimensionError> {
+    let mut mat = Matrix::from_vec(3, 2, vec![1, 2, 3, 4, 5, 6])?;
+    let rows: Vec<&[i32]> = mat.row_iter().collect();
+    assert_eq!(rows, vec![&[1, 2][..], &[3, 4], &[5, 6]]);
+    for (i, row) in mat.row_iter_mut().enumerate() {
+        row[0] *= i as i32;
+    }
+    assert_eq!(mat, matrix! {{0, 2}, {3, 4}, {10, 6}});
+    assert_eq!(
+        mat.clone().into_rows().collect::<Vec<_>>(),
+        vec![vec![0, 2], vec![3, 4], vec![10, 6]]
+    );
+    Ok(())
+}
+
+#[test]
+fn col_iter() -> Result<(), DimensionError> {
+    let mat = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6])?;
+    let cols: Vec<Vec<&i32>> = mat.col_iter().collect();
+    assert_eq!(cols, vec![vec![&1, &4], vec![&2, &5], vec![&3, &6]]);
+    let col_sums: Vec<i32> = mat.col_iter().map(|c| c.into_iter().sum()).collect();
+    assert_eq!(col_sums, vec![5, 7, 9]);
+    // columns of a matrix are the rows of its transpose
+    assert_eq!(
+        mat.clone().into_cols().collect::<Vec<_>>(),
+        mat.transpose().into_rows().collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[test]
+fn entry_iter() -> Result<(), DimensionError> {
+    let mut mat = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6])?;
+    assert_eq!(mat.iter().sum::<i32>(), 21);
+    assert_eq!(mat.iter().max(), Some(&6));
+    mat.iter_mut().for_each(|x| *x -= 1);
+    assert_eq!(
+        mat.iter().copied().collect::<Vec<_>>(),
+        (0..6).collect::<Vec<_>>()
+    );
+
+    for x in &mut mat {
+        *x *= 2;
+    }
+    let mut count = 0;
+    for x in &mat {
+        assert_eq!(x % 2, 0);
+        count += 1;
+    }
+    assert_eq!(count, 6);
+    assert_eq!(mat.into_iter().collect::<Vec<_>>(), vec![0, 2, 4, 6, 8, 10]);
+    Ok(())
+}
+
+#[test]
+fn indexed_iter() -> Result<(), DimensionError> {
+    let mat = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6])?;
+    for ((i, j), x) in mat.indexed_iter() {
+        assert_eq!(mat[i][j], *x);
+    }
+    let positions: Vec<(usize, usize)> = mat.indexed_iter().map(|(pos, _)| pos).collect();
+    assert_eq!(
+        positions,
+        vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]
+    );
+    Ok(())
+}
+
+#[test]
+fn map_into() -> Result<(), DimensionError> {
+    let words = Matrix::from_vec(
+        1,
+        3,
+        vec![String::from("a"), String::from("bb"), String::from("ccc")],
+    )?;
+    let lengths = words.clone().map_into(|w| w.len());
+    assert_eq!(lengths, Matrix::from_vec(1, 3, vec![1, 2, 3])?);
+    assert_eq!(words.map(|w| w.len()), lengths);
+
+    let mat: Matrix<f64> = matrix! {{1.4, -2.6}, {0.5, 3.2}};
+    assert_eq!(mat.map_into(f64::round), matrix! {{1.0, -3.0}, {1.0, 3.0}});
+    Ok(())
+}
+
+#[test]
+fn row_col_iter_vectors() -> Result<(), DimensionError> {
+    let row = Matrix::from_vec(1, 4, vec![1, 2, 3, 4])?;
+    let col = row.transpose();
+    assert_eq!(row.row_iter().count(), 1);
+    assert_eq!(row.col_iter().count(), 4);
+    assert_eq!(col.row_iter().count(), 4);
+    assert_eq!(col.col_iter().count(), 1);
+    assert_eq!(col.col_iter().next(), Some(vec![&1, &2, &3, &4]));
+    assert_eq!(row.row_iter().next(), Some(&[1, 2, 3, 4][..]));
+
+    // maximum of each column of a 3x4 matrix
+    let mat = Matrix::from_vec(3, 4, vec![3, 8, 1, 0, 7, 2, 9, 4, 5, 6, 2, 11])?;
+    let max: Vec<i32> = mat.col_iter().map(|c| **c.iter().max().unwrap()).collect();
+    assert_eq!(max, vec![7, 8, 9, 11]);
+    Ok(())
+}
+
+#[test]
+fn fold() -> Result<(), DimensionError> {
+    let mat: Matrix<f64> = matrix! {{1.0, -2.0, 3.0}, {4.0, 5.0, -6.0}};
+    assert_eq!(mat.fold_rows(0.0, |sum, x| sum + x), vec![2.0, 3.0]);
+    assert_eq!(mat.fold_cols(0.0, |sum, x| sum + x), vec![5.0, 3.0, -3.0]);
+    assert_eq!(
+        mat.fold_cols(0.0, |max: f64, x| max.max(x.abs())),
+        vec![4.0, 5.0, 6.0]
+    );
+    // the column folds visit each column top to bottom
+    let order = mat.fold_cols(Vec::new(), |mut seen, x| {
+        seen.push(*x);
+        seen
+    });
+    assert_eq!(order[2], vec![3.0, -6.0]);
+    assert_eq!(
+        mat.transpose().fold_rows(0.0, |sum, x| sum + x),
+        mat.fold_cols(0.0, |sum, x| sum + x)
+    );
+    Ok(())
+}
+
+#[test]
+fn axis_reductions() -> Result<(), DimensionError> {
+    let mat: Matrix<f64> = matrix! {{1.0, -2.0, 3.0}, {4.0, 5.0, -6.0}, {0.0, 7.0, 2.0}};
+    assert_eq!(mat.row_sum(), vec![2.0, 3.0, 9.0]);
+    assert_eq!(mat.col_sum(), vec![5.0, 10.0, -1.0]);
+    assert_eq!(mat.row_max(), vec![3.0, 5.0, 7.0]);
+    assert_eq!(mat.col_max(), vec![4.0, 7.0, 3.0]);
+    assert_eq!(mat.row_min(), vec![-2.0, -6.0, 0.0]);
+    assert_eq!(mat.col_min(), vec![0.0, -2.0, -6.0]);
+    assert_eq!(mat.transpose().row_max(), mat.col_max());
+
+    let row = Matrix::from_vec(1, 3, vec![2, 9, 4])?;
+    assert_eq!(row.row_max(), vec![9]);
+    assert_eq!(row.col_max(), vec![2, 9, 4]);
+    assert_eq!(row.col_sum(), vec![2, 9, 4]);
+    Ok(())
+}
@@ -0,0 +1,153 @@
+mod common;
+
+use common::random;
+use libmat::{err::DimensionError, mat::Matrix};
+use std::time::Instant;
+
+/// Reference triple loop, summing every entry from left to right.
+fn naive_mul(a: &Matrix<f64>, b: &Matrix<f64>) -> Matrix<f64> {
+    Matrix::from_fn(a.rows(), b.cols(), |i, j| {
+        (1..a.cols()).fold(a[i][0] * b[0][j], |acc, k| acc + a[i][k] * b[k][j])
+    })
+    .unwrap()
+}
+
+#[test]
+fn blocked_matches_naive() -> Result<(), DimensionError> {
+    let shapes = [
+        (1, 1, 1),
+        (3, 5, 2),
+        (7, 1, 9),
+        (64, 64, 64),
+        (65, 130, 67),
+        (100, 3, 129),
+    ];
+    for (seed, &(m, k, n)) in shapes.iter().enumerate() {
+        let a = random(m, k, seed as u64);
+        let b = random(k, n, seed as u64 + 100);
+        let expected = naive_mul(&a, &b);
+        assert_eq!((a.clone() * b.clone())?, expected);
+        assert_eq!(&a * &b, Ok(expected.clone()));
+        for &block_size in [1, 3, 4, 16, 1000].iter() {
+            assert_eq!(a.checked_mul_blocked(&b, block_size)?, expected);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn blocked_integers() -> Result<(), DimensionError> {
+    let a: Matrix<i64> = Matrix::from_fn(9, 6, |i, j| (i as i64 * 3 - j as i64) % 5)?;
+    let b: Matrix<i64> = Matrix::from_fn(6, 11, |i, j| (i as i64 + 2 * j as i64) % 7 - 3)?;
+    let expected = Matrix::from_fn(9, 11, |i, j| (0..6).map(|k| a[i][k] * b[k][j]).sum())?;
+    assert_eq!(a.checked_mul_blocked(&b, 2)?, expected);
+    assert_eq!((a * b)?, expected);
+    Ok(())
+}
+
+#[test]
+fn blocked_dimension_mismatch() {
+    let a = random(3, 4, 1);
+    assert_eq!(
+        a.checked_mul_blocked(&a, 2),
+        Err(DimensionError::NoMatch(
+            a.dims(),
+            a.dims(),
+            "multiply".to_owned()
+        ))
+    );
+}
+
+#[test]
+#[should_panic]
+fn blocked_zero_block_size() {
+    let a = random(2, 2, 1);
+    let _ = a.checked_mul_blocked(&a, 0);
+}
+
+#[test]
+fn parallel_matches_serial() -> Result<(), DimensionError> {
+    let a = random(300, 300, 1);
+    let b = random(300, 300, 2);
+    let serial = (&a * &b)?;
+    let parallel = a.par_mul(&b)?;
+    let bits = |m: &Matrix<f64>| {
+        m.row_iter()
+            .flatten()
+            .map(|x| x.to_bits())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(bits(&parallel), bits(&serial));
+
+    let c = random(7, 300, 3);
+    assert_eq!(c.par_mul(&a)?, (&c * &a)?);
+    assert_eq!(
+        c.par_mul(&c),
+        Err(DimensionError::NoMatch(
+            c.dims(),
+            c.dims(),
+            "multiply".to_owned()
+        ))
+    );
+    Ok(())
+}
+
+/// Compare the naive with the blocked multiplication. Run it with
+/// `cargo test --release --test multiplication -- --ignored --nocapture`.
+#[test]
+#[ignore]
+fn bench_mul() {
+    for &n in [256, 512, 1024].iter() {
+        let a = random(n, n, 1);
+        let b = random(n, n, 2);
+        let start = Instant::now();
+        let naive = naive_mul(&a, &b);
+        let naive_time = start.elapsed();
+        let start = Instant::now();
+        let blocked = (&a * &b).unwrap();
+        let blocked_time = start.elapsed();
+        assert_eq!(naive, blocked);
+        println!("{n}x{n}: naive {naive_time:?}, blocked {blocked_time:?}");
+    }
+}
+
+/// Compare the serial with the parallel multiplication. Run it with
+/// `cargo test --release --test multiplication -- --ignored --nocapture`.
+#[test]
+#[ignore]
+fn bench_par_mul() {
+    for &n in [512, 1024].iter() {
+        let a = random(n, n, 1);
+        let b = random(n, n, 2);
+        let start = Instant::now();
+        let serial = (&a * &b).unwrap();
+        let serial_time = start.elapsed();
+        let start = Instant::now();
+        let parallel = a.par_mul(&b).unwrap();
+        let parallel_time = start.elapsed();
+        assert_eq!(serial, parallel);
+        println!("{n}x{n}: serial {serial_time:?}, parallel {parallel_time:?}");
+    }
+}
+
+#[test]
+fn transposed_products() -> Result<(), DimensionError> {
+    let shapes = [(1, 1, 1), (5, 3, 2), (9, 7, 13), (70, 65, 66)];
+    for (seed, &(m, k, n)) in shapes.iter().enumerate() {
+        let a = random(k, m, seed as u64);
+        let b = random(k, n, seed as u64 + 100);
+        assert_eq!(a.mul_transpose_left(&b)?, (a.transpose() * b.clone())?);
+        let c = random(n, m, seed as u64 + 200);
+        let d = random(k, m, seed as u64 + 300);
+        assert_eq!(c.mul_transpose_right(&d)?, (c.clone() * d.transpose())?);
+    }
+    // Gram matrices are symmetric
+    let a = random(20, 6, 7);
+    assert!(a.mul_transpose_left(&a)?.is_symmetric());
+    assert!(a.mul_transpose_right(&a)?.is_symmetric());
+
+    let b = random(4, 6, 8);
+    assert_eq!(a.mul_transpose_left(&b), a.transpose() * b.clone());
+    assert_eq!(b.mul_transpose_right(&random(6, 4, 9)), b * random(4, 6, 9));
+    Ok(())
+}
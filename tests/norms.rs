@@ -0,0 +1,80 @@
+use libmat::{err::DimensionError, mat::Matrix, matrix};
+
+#[test]
+fn norms() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{1.0, -2.0, 3.0}, {-4.0, 5.0, -6.0}};
+    assert!((a.frobenius_norm() - 91_f64.sqrt()).abs() < 1e-12);
+    assert_eq!(a.one_norm(), 9.0);
+    assert_eq!(a.inf_norm(), 15.0);
+    assert_eq!(a.max_norm(), 6.0);
+    let (_, s, _) = a.svd()?;
+    assert_eq!(a.two_norm(), s[0]);
+    assert!(a.two_norm() <= a.frobenius_norm());
+    Ok(())
+}
+
+#[test]
+fn norms_identity() -> Result<(), DimensionError> {
+    let a: Matrix<f32> = Matrix::one(4)?;
+    assert_eq!(a.frobenius_norm(), 2.0);
+    assert_eq!(a.one_norm(), 1.0);
+    assert_eq!(a.inf_norm(), 1.0);
+    assert_eq!(a.max_norm(), 1.0);
+    assert!((a.two_norm() - 1.0).abs() < 1e-6);
+    Ok(())
+}
+
+#[test]
+fn norms_signed() {
+    let a = matrix! {{3, -7, 1}, {-2, 0, -5}};
+    // column sums 5, 7, 6 and row sums 11, 7
+    assert_eq!(a.one_norm(), 7);
+    assert_eq!(a.inf_norm(), 11);
+    assert_eq!(a.max_norm(), 7);
+    assert_eq!((a * -1).max_norm(), 7);
+    let b: Matrix<f64> = matrix! {{-0.5, 0.25, -8.0}, {1.5, -2.0, 0.0}};
+    assert_eq!(b.one_norm(), 8.0);
+    assert_eq!(b.inf_norm(), 8.75);
+    assert_eq!(b.max_norm(), 8.0);
+}
+
+#[test]
+fn frobenius_inner() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{1.0, -2.0, 0.5}, {3.0, 0.0, 4.0}};
+    let b: Matrix<f64> = matrix! {{2.0, 1.0, -2.0}, {0.0, 5.0, 1.0}};
+    let inner = a.frobenius_inner(&b)?;
+    assert_eq!(inner, 3.0);
+    assert_eq!(inner, b.frobenius_inner(&a)?);
+    assert_eq!(inner, (a.transpose() * b.clone())?.trace()?);
+    assert!((a.frobenius_inner(&a)? - a.frobenius_norm().powi(2)).abs() < 1e-12);
+    let c: Matrix<f64> = Matrix::one(2)?;
+    assert_eq!(
+        a.frobenius_inner(&c),
+        Err(DimensionError::NoMatch(
+            a.dims(),
+            c.dims(),
+            "take the inner product of".to_owned()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn cond() -> Result<(), DimensionError> {
+    assert_eq!(Matrix::<f64>::one(4)?.cond()?, Some(1.0));
+    let d: Matrix<f64> = Matrix::diag_with(3, &[2.0, -4.0, 0.5])?;
+    assert!((d.cond()?.unwrap() - 8.0).abs() < 1e-12);
+
+    // rows are nearly parallel
+    let a: Matrix<f64> = matrix! {{1.0, 1.0}, {1.0, 1.0 + 1e-10}};
+    let cond = a.cond()?.unwrap();
+    assert!(cond > 1e10);
+    // cond(A) >= 1 for every invertible A
+    let b: Matrix<f64> = matrix! {{4.0, -2.0, 1.0}, {3.0, 6.0, -4.0}, {2.0, 1.0, 8.0}};
+    assert!(b.cond()?.unwrap() >= 1.0);
+
+    assert_eq!(matrix! {{1.0, 2.0}, {2.0, 4.0}}.cond()?, None);
+    let c: Matrix<f64> = Matrix::zero(2, 3)?;
+    assert_eq!(c.cond(), Err(DimensionError::NoSquare));
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use libmat::{
+    err::DimensionError,
+    mat::{Conjugate, Matrix},
+    matrix,
+};
+use num_traits::Inv;
+
+#[test]
+fn symmetric() -> Result<(), DimensionError> {
+    assert!(Matrix::<i32>::one(3)?.is_symmetric());
+    assert!(matrix! {{1, 2, 3}, {2, 4, 5}, {3, 5, 6}}.is_symmetric());
+    assert!(!matrix! {{1, 2, 3}, {2, 4, 5}, {3, 6, 6}}.is_symmetric());
+    assert!(!Matrix::new(2, 3, 1)?.is_symmetric());
+    assert!(Matrix::new(1, 1, 0)?.is_symmetric());
+    Ok(())
+}
+
+#[test]
+fn triangular() -> Result<(), DimensionError> {
+    let upper = matrix! {{1, 2, 3}, {0, 4, 5}, {0, 0, 6}};
+    assert!(upper.is_upper_triangular());
+    assert!(!upper.is_lower_triangular());
+    assert!(upper.transpose().is_lower_triangular());
+    assert!(!upper.is_diagonal());
+
+    let wide = matrix! {{1, 2, 3, 4}, {0, 5, 6, 7}};
+    assert!(wide.is_upper_triangular());
+    assert!(wide.transpose().is_lower_triangular());
+    let tall = matrix! {{1, 0}, {0, 2}, {0, 0}};
+    assert!(tall.is_diagonal());
+    assert!(!matrix! {{1, 0}, {0, 2}, {3, 0}}.is_diagonal());
+
+    assert!(Matrix::<i32>::zero(3, 2)?.is_diagonal());
+    assert!(Matrix::from_diag(&[1, 2, 3])?.is_diagonal());
+    Ok(())
+}
+
+#[test]
+fn identity() -> Result<(), DimensionError> {
+    for n in 1..4 {
+        assert!(Matrix::<i32>::one(n)?.is_identity());
+        assert!(Matrix::<f64>::one(n)?.is_identity());
+    }
+    assert!(matrix! {{1}}.is_identity());
+    assert!(!matrix! {{0}}.is_identity());
+    assert!(!Matrix::<i32>::zero(2, 2)?.is_identity());
+    assert!(!matrix! {{1, 0, 0}, {0, 1, 0}}.is_identity());
+    assert!(!matrix! {{1, 0}, {1, 1}}.is_identity());
+    // zero matrices are triangular in both directions and diagonal
+    let zero: Matrix<i32> = Matrix::zero(3, 3)?;
+    assert!(zero.is_upper_triangular() && zero.is_lower_triangular() && zero.is_diagonal());
+    assert!(zero.is_symmetric());
+    Ok(())
+}
+
+#[test]
+fn orthogonal() -> Result<(), DimensionError> {
+    assert!(Matrix::<f64>::one(4)?.is_orthogonal(0.0));
+    let a: Matrix<f64> = matrix! {{2.0, -1.0, 0.0}, {1.0, 3.0, 1.0}, {0.0, 1.0, 4.0}};
+    let (q, _) = a.qr()?;
+    assert!(q.is_orthogonal(1e-12));
+    // permutation matrices are orthogonal
+    let p: Matrix<f64> = matrix! {{0.0, 1.0, 0.0}, {0.0, 0.0, 1.0}, {1.0, 0.0, 0.0}};
+    assert!(p.is_orthogonal(0.0));
+    assert!(!(p * 2.0).is_orthogonal(1e-12));
+    // orthonormal columns are not enough for non-square matrices
+    let (q_thin, _) = matrix! {{1.0, 0.0}, {1.0, 1.0}, {0.0, 1.0}}.qr()?;
+    assert!(!q_thin.is_orthogonal(1e-12));
+    Ok(())
+}
+
+#[test]
+fn approx_eq() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{1.0, 2.0}, {3.0, 4.0}};
+    let inv = a.clone().inv()?.unwrap();
+    let product = (a.clone() * inv)?;
+    assert!(product.approx_eq(&Matrix::one(2)?, 1e-12));
+    assert!(a.approx_eq(&a, 0.0));
+    assert!(!a.approx_eq(&(a.clone() + 1e-6), 1e-9));
+    assert!(a.approx_eq(&(a.clone() + 1e-6), 1e-5));
+    assert!(!a.approx_eq(&a.transpose(), 1e-12));
+    assert!(!a.approx_eq(&Matrix::new(1, 4, 1.0)?, f64::INFINITY));
+    let nan: Matrix<f64> = Matrix::new(2, 2, f64::NAN)?;
+    assert!(!nan.approx_eq(&nan, f64::INFINITY));
+    Ok(())
+}
+
+#[test]
+fn real_conjugate_transpose() -> Result<(), DimensionError> {
+    let mats: [Matrix<f64>; 3] = [
+        matrix! {{1.0, -2.0, 3.5}, {0.0, 4.0, -5.0}},
+        Matrix::from_fn(4, 4, |i, j| (i as f64 - 2.0 * j as f64).sin())?,
+        matrix! {{7.0}},
+    ];
+    for mat in mats.iter() {
+        assert_eq!(mat.conjugate_transpose(), mat.transpose());
+        assert_eq!(mat.is_hermitian(0.0), mat.is_symmetric());
+    }
+    let int = matrix! {{1, -2}, {3, 4}};
+    assert_eq!(int.conjugate_transpose(), int.transpose());
+    assert!(!int.is_hermitian(0u32));
+    assert!(matrix! {{i32::MIN, 2}, {2, i32::MAX}}.is_hermitian(0));
+    assert_eq!(i8::MIN.modulus(), 128u8);
+
+    let (c, s) = (0.6_f64, 0.8_f64);
+    let rot = matrix! {{c, -s, 0.0}, {s, c, 0.0}, {0.0, 0.0, 1.0}};
+    assert!(rot.is_unitary(1e-12));
+    assert!(!(rot.clone() * 2.0).is_unitary(1e-12));
+    assert!(!matrix! {{1.0, 0.0}}.is_unitary(1e-12));
+    assert!(!matrix! {{1.0, 0.0}}.is_hermitian(1e-12));
+    Ok(())
+}
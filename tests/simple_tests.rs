@@ -1,4 +1,13 @@
-use libmat::{err::DimensionError, mat::Matrix};
+mod common;
+
+use common::assert_mat_eq;
+use libmat::{
+    err::{DimensionError, ParseMatrixError},
+    mat::{LatexEnv, Matrix, Vector},
+    matrix, vector,
+};
+use num_traits::Inv;
+use std::convert::TryFrom;
 
 #[test]
 fn construct() -> Result<(), DimensionError> {
@@ -6,6 +15,39 @@ fn construct() -> Result<(), DimensionError> {
     assert_eq!(m, Matrix::from_vec(2, 2, vec![1.0, 1.0, 1.0, 1.0])?);
     Ok(())
 }
+#[test]
+fn from_fn() -> Result<(), DimensionError> {
+    let hilbert: Matrix<f64> = Matrix::from_fn(3, 3, |i, j| 1.0 / (i + j + 1) as f64)?;
+    assert_eq!(hilbert[(0, 0)], 1.0);
+    assert_eq!(hilbert[(2, 1)], 0.25);
+    assert!(hilbert.is_symmetric());
+
+    let checkerboard = Matrix::from_fn(2, 3, |i, j| (i + j) % 2 == 0)?;
+    assert_eq!(
+        checkerboard,
+        Matrix::from_vec(2, 3, vec![true, false, true, false, true, false])?
+    );
+
+    // called once per entry in row-major order
+    let mut calls = Vec::new();
+    let m = Matrix::from_fn(2, 2, |i, j| {
+        calls.push((i, j));
+        calls.len()
+    })?;
+    assert_eq!(calls, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    assert_eq!(m, matrix! {{1, 2}, {3, 4}});
+
+    assert_eq!(
+        Matrix::from_fn(0, 2, |i, j| i + j),
+        Err(DimensionError::InvalidDimensions)
+    );
+    assert_eq!(
+        Matrix::from_fn(2, 0, |i, j| i + j),
+        Err(DimensionError::InvalidDimensions)
+    );
+    Ok(())
+}
+
 #[test]
 fn identity() -> Result<(), DimensionError> {
     let mat_a: Matrix<f32> = Matrix::<f32>::one(3)?;
@@ -13,6 +55,12 @@ fn identity() -> Result<(), DimensionError> {
         mat_a,
         Matrix::from_vec(3, 3, vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0])?
     );
+    assert_eq!(Matrix::identity(3)?, mat_a);
+    assert!(Matrix::<i32>::identity(5)?.is_identity());
+    assert_eq!(
+        Matrix::<i32>::identity(0),
+        Err(DimensionError::InvalidDimensions)
+    );
     Ok(())
 }
 
@@ -65,6 +113,20 @@ fn scale() -> Result<(), DimensionError> {
     Ok(())
 }
 #[test]
+fn scalar_broadcast() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f64> = matrix! {{1.0, -2.0}, {0.5, 4.0}};
+    assert_eq!(mat_a.clone() + 1.0, matrix! {{2.0, -1.0}, {1.5, 5.0}});
+    assert_eq!(mat_a.clone() - 1.0, matrix! {{0.0, -3.0}, {-0.5, 3.0}});
+    assert_eq!(mat_a.clone() * 2.0, matrix! {{2.0, -4.0}, {1.0, 8.0}});
+    assert_eq!(mat_a.clone() / 2.0, matrix! {{0.5, -1.0}, {0.25, 2.0}});
+    assert_eq!(2.0 * mat_a.clone(), mat_a.clone() * 2.0);
+    assert_eq!(1.0 + mat_a.clone(), mat_a.clone() + 1.0);
+    assert_eq!(1.0 - mat_a.clone(), -(mat_a - 1.0));
+    let mat_u: Matrix<u8> = Matrix::new(2, 2, 3)?;
+    assert_eq!(5 - mat_u, Matrix::new(2, 2, 2)?);
+    Ok(())
+}
+#[test]
 fn add() -> Result<(), DimensionError> {
     let mat_a: Matrix<f32> = Matrix::<f32>::one(3)?;
     let mat_d: Matrix<f32> = Matrix::<f32>::diag(3, 2.0)?;
@@ -78,3 +140,732 @@ fn sub() -> Result<(), DimensionError> {
     assert_eq!((mat_a.clone() - mat_a)?, mat_b);
     Ok(())
 }
+#[test]
+fn neg() -> Result<(), DimensionError> {
+    let a = matrix! {{1, -2, 3}, {0, 5, -6}};
+    let expected = matrix! {{-1, 2, -3}, {0, -5, 6}};
+    assert_eq!(-a.clone(), expected);
+    assert_eq!(-&a, expected);
+    assert_eq!(-(-&a), a);
+    assert_eq!((-&a).dims(), a.dims());
+    assert_eq!((&a + &(-&a))?, Matrix::zero(2, 3)?);
+    let b = matrix! {{2, 2, 2}, {2, 2, 2}};
+    assert_eq!((b.clone() - &a)?, (-&a + b.clone())?);
+    let x = matrix! {{1}, {1}, {1}};
+    assert_eq!((-&a * &x)?, matrix! {{-2}, {1}});
+    let f: Matrix<f64> = matrix! {{0.5, -1.5}};
+    assert_eq!(-&f, matrix! {{-0.5, 1.5}});
+    Ok(())
+}
+
+#[test]
+fn hadamard() -> Result<(), DimensionError> {
+    let mut mat_a = matrix! {{1, 2, 3}, {4, 5, 6}};
+    let mat_b = matrix! {{2, 2, 0}, {-1, 1, 3}};
+    assert_eq!(mat_a.hadamard(&mat_b)?, matrix! {{2, 4, 0}, {-4, 5, 18}});
+    mat_a.hadamard_assign(&mat_b)?;
+    assert_eq!(mat_a, matrix! {{2, 4, 0}, {-4, 5, 18}});
+    let mat_c = Matrix::one(2)?;
+    assert_eq!(
+        mat_a.hadamard(&mat_c),
+        Err(DimensionError::NoMatch(
+            mat_a.dims(),
+            mat_c.dims(),
+            "multiply elementwise".to_owned()
+        ))
+    );
+    Ok(())
+}
+#[test]
+fn hadamard_div() -> Result<(), DimensionError> {
+    let mat_a = matrix! {{6, 8}, {-9, 7}};
+    let mat_b = matrix! {{3, 2}, {3, 7}};
+    assert_eq!(mat_a.hadamard_div(&mat_b)?, matrix! {{2, 4}, {-3, 1}});
+    assert_eq!((mat_a.hadamard_div(&mat_b)?).hadamard(&mat_b)?, mat_a);
+    let mat_c = matrix! {{1, 2}};
+    assert_eq!(
+        mat_a.hadamard_div(&mat_c),
+        Err(DimensionError::NoMatch(
+            mat_a.dims(),
+            mat_c.dims(),
+            "divide elementwise".to_owned()
+        ))
+    );
+    Ok(())
+}
+#[test]
+fn pow() -> Result<(), DimensionError> {
+    let mat = matrix! {{1, 2}, {3, 4}};
+    let mut naive = Matrix::one(2)?;
+    for exp in 0..12 {
+        assert_eq!(mat.pow(exp)?, naive);
+        naive = (naive * mat.clone())?;
+    }
+    let markov: Matrix<f64> = matrix! {{0.9, 0.1}, {0.5, 0.5}};
+    let stationary = markov.pow(1000)?;
+    assert!((stationary[0][0] - 5.0 / 6.0).abs() < 1e-12);
+    assert!((stationary[1][1] - 1.0 / 6.0).abs() < 1e-12);
+    assert_eq!(Matrix::new(2, 3, 1)?.pow(2), Err(DimensionError::NoSquare));
+    Ok(())
+}
+#[test]
+fn powi() -> Result<(), DimensionError> {
+    let mat: Matrix<f64> = matrix! {{2.0, 1.0}, {1.0, 1.0}};
+    assert_eq!(mat.powi(3)?, Some(mat.pow(3)?));
+    assert_eq!(mat.powi(0)?, Some(Matrix::one(2)?));
+    // inverse is {{1, -1}, {-1, 2}}
+    assert_mat_eq(
+        &mat.powi(-1)?.unwrap(),
+        &matrix! {{1.0, -1.0}, {-1.0, 2.0}},
+        1e-12,
+    );
+    assert_mat_eq(
+        &mat.powi(-2)?.unwrap(),
+        &matrix! {{2.0, -3.0}, {-3.0, 5.0}},
+        1e-12,
+    );
+    assert_eq!(matrix! {{1.0, 2.0}, {2.0, 4.0}}.powi(-3)?, None);
+    assert_eq!(
+        Matrix::<f64>::new(3, 1, 1.0)?.powi(-1),
+        Err(DimensionError::NoSquare)
+    );
+    Ok(())
+}
+#[test]
+fn kronecker() -> Result<(), DimensionError> {
+    let mat_a = matrix! {{1, 2, 3}};
+    let mat_b = matrix! {{1}, {-1}};
+    let prod = mat_a.kronecker(&mat_b);
+    assert_eq!(prod, matrix! {{1, 2, 3}, {-1, -2, -3}});
+    assert_eq!(mat_b.kronecker(&mat_a), prod);
+    let mat_i: Matrix<i32> = Matrix::one(2)?;
+    assert_eq!(
+        mat_i.kronecker(&mat_b),
+        matrix! {{1, 0}, {-1, 0}, {0, 1}, {0, -1}}
+    );
+    Ok(())
+}
+#[test]
+fn kronecker_identity() -> Result<(), DimensionError> {
+    let mat_a = matrix! {{1, 2, 3}, {4, 5, 6}};
+    let mat_i: Matrix<i32> = Matrix::one(2)?;
+    let zero: Matrix<i32> = Matrix::zero(2, 3)?;
+    assert_eq!(
+        mat_i.kronecker(&mat_a),
+        mat_a.hstack(&zero)?.vstack(&zero.hstack(&mat_a)?)?
+    );
+    Ok(())
+}
+#[test]
+fn outer() -> Result<(), DimensionError> {
+    let a = [1, -2, 3];
+    let b = [4, 5];
+    let prod = Matrix::outer(&a, &b);
+    assert_eq!(prod, matrix! {{4, 5}, {-8, -10}, {12, 15}});
+    // outer product equals the matrix product of a column and a row
+    let col = Matrix::from_vec(3, 1, a.to_vec())?;
+    let row = Matrix::from_vec(1, 2, b.to_vec())?;
+    assert_eq!((col * row)?, prod);
+    assert_eq!(Matrix::outer(&b, &a), prod.transpose());
+    Ok(())
+}
+#[test]
+fn toeplitz() -> Result<(), DimensionError> {
+    let mat = Matrix::toeplitz(&[1, 2], &[1, 3, 4])?;
+    assert_eq!(mat, matrix! {{1, 3, 4}, {2, 1, 3}});
+    // entries are constant along every diagonal
+    let tall = Matrix::toeplitz(&[0, 1, 2, 3], &[0, -1])?;
+    for i in 1..tall.rows() {
+        for j in 1..tall.cols() {
+            assert_eq!(tall[i][j], tall[i - 1][j - 1]);
+        }
+    }
+    assert_eq!(Matrix::toeplitz(&[7], &[7])?, matrix! {7});
+    assert_eq!(
+        Matrix::toeplitz(&[1, 2], &[2, 1]),
+        Err(DimensionError::InconsistentCorner)
+    );
+    assert_eq!(
+        Matrix::<i32>::toeplitz(&[], &[1]),
+        Err(DimensionError::InvalidDimensions)
+    );
+    Ok(())
+}
+#[test]
+fn vandermonde() -> Result<(), DimensionError> {
+    let mat: Matrix<f64> = Matrix::vandermonde(&[-1.0, 0.5, 2.0], 3);
+    assert_eq!((mat.rows(), mat.cols()), (3, 4));
+    for (i, x) in [-1.0f64, 0.5, 2.0].iter().enumerate() {
+        for j in 0..4 {
+            assert_eq!(mat[i][j], x.powi(j as i32));
+        }
+    }
+    assert_eq!(Matrix::vandermonde(&[5, 6], 0), matrix! {{1}, {1}});
+    // interpolate p(x) = 1 + 2x + 3x² through three points
+    let v: Matrix<f64> = Matrix::vandermonde(&[0.0, 1.0, 2.0], 2);
+    let coeffs = v.solve_mat(&matrix! {{1.0}, {6.0}, {17.0}})?.unwrap();
+    assert!(coeffs.approx_eq(&matrix! {{1.0}, {2.0}, {3.0}}, 1e-12));
+    Ok(())
+}
+#[test]
+fn hilbert() -> Result<(), DimensionError> {
+    let mat: Matrix<f64> = Matrix::hilbert(4);
+    assert!(mat.is_symmetric());
+    assert_eq!(mat[3][3], 1.0 / 7.0);
+    assert_eq!(Matrix::<f64>::hilbert_inv(1), matrix! {{1.0}});
+    // the entries of the inverse are exact integers, so the product is close to the identity
+    for n in 1..=8 {
+        let prod = (Matrix::<f64>::hilbert(n) * Matrix::hilbert_inv(n))?;
+        assert!(prod.approx_eq(&Matrix::one(n)?, 1e-6), "n = {}", n);
+    }
+    let inv: Matrix<f64> = Matrix::hilbert_inv(5);
+    assert!(inv.iter().all(|x| x.fract() == 0.0));
+    assert_eq!(inv[4][4], 44100.0);
+    Ok(())
+}
+#[test]
+fn kronecker_rectangular() -> Result<(), DimensionError> {
+    // 2x3 ⊗ 3x2 = 6x6, checked entrywise: (A ⊗ B)[p·i + k][q·j + l] = A[i][j]·B[k][l]
+    let mat_a = matrix! {{1, -2, 3}, {0, 4, 5}};
+    let mat_b = matrix! {{7, 1}, {-1, 2}, {3, 6}};
+    let prod = mat_a.kronecker(&mat_b);
+    assert_eq!((prod.rows(), prod.cols()), (6, 6));
+    for i in 0..2 {
+        for j in 0..3 {
+            for k in 0..3 {
+                for l in 0..2 {
+                    assert_eq!(prod[3 * i + k][2 * j + l], mat_a[i][j] * mat_b[k][l]);
+                }
+            }
+        }
+    }
+    // column vector ⊗ row vector is the outer product
+    let col = matrix! {{1}, {2}};
+    let row = matrix! {{3, 4, 5}};
+    assert_eq!(col.kronecker(&row), (col.clone() * row.clone())?);
+    Ok(())
+}
+#[test]
+fn stack() -> Result<(), DimensionError> {
+    let mat_a = matrix! {{1, 2}, {3, 4}};
+    let mat_i = Matrix::one(2)?;
+    // augmented matrix for row reduction
+    assert_eq!(mat_a.hstack(&mat_i)?, matrix! {{1, 2, 1, 0}, {3, 4, 0, 1}});
+    assert_eq!(
+        mat_a.vstack(&mat_i)?,
+        matrix! {{1, 2}, {3, 4}, {1, 0}, {0, 1}}
+    );
+    assert_eq!(
+        mat_a.hstack(&mat_i)?.transpose(),
+        mat_a.transpose().vstack(&mat_i)?
+    );
+
+    let row = matrix! {{5, 6, 7}};
+    assert_eq!(
+        mat_a.hstack(&row),
+        Err(DimensionError::NoMatch(
+            mat_a.dims(),
+            row.dims(),
+            "stack horizontally".to_owned()
+        ))
+    );
+    assert_eq!(
+        mat_a.vstack(&row),
+        Err(DimensionError::NoMatch(
+            mat_a.dims(),
+            row.dims(),
+            "stack vertically".to_owned()
+        ))
+    );
+    Ok(())
+}
+#[test]
+fn map() -> Result<(), DimensionError> {
+    let mat = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6])?;
+    let squares = mat.map(|x| x * x);
+    assert_eq!(squares, Matrix::from_vec(2, 3, vec![1, 4, 9, 16, 25, 36])?);
+    let floats: Matrix<f64> = mat.map(|x| *x as f64);
+    assert_eq!(floats.dims(), mat.dims());
+    assert_eq!(floats[1][2], 6.0);
+
+    // entries are visited in row-major order
+    let mut order = Vec::new();
+    let mut counted = mat.clone();
+    counted.map_mut(|x| {
+        order.push(*x);
+        *x = order.len() as i32 * 10;
+    });
+    assert_eq!(order, vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(counted, mat.map(|x| x * 10));
+    Ok(())
+}
+#[test]
+fn zip_with() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f64> = matrix! {{1.0, 2.5, -1.0}, {0.0, 3.0, 7.0}};
+    let mat_b: Matrix<f64> = matrix! {{2.0, 2.5, -3.0}, {1.0, 1.0, 9.0}};
+    let less: Matrix<u8> = mat_a.zip_with(&mat_b, |a, b| (a < b) as u8)?;
+    assert_eq!(less, matrix! {{1, 0, 0}, {1, 0, 1}});
+    assert_eq!(
+        mat_a.zip_with(&mat_b, |a, b| a - b)?,
+        (mat_a.clone() - mat_b.clone())?
+    );
+
+    let mut order = Vec::new();
+    mat_a.zip_with(&less, |a, b| order.push((*a, *b)))?;
+    assert_eq!(order[1], (2.5, 0));
+    assert_eq!(order[5], (7.0, 1));
+
+    let mat_c: Matrix<f64> = Matrix::one(2)?;
+    assert_eq!(
+        mat_a.zip_with(&mat_c, |a, b| a + b),
+        Err(DimensionError::NoMatch(
+            mat_a.dims(),
+            mat_c.dims(),
+            "combine".to_owned()
+        ))
+    );
+    Ok(())
+}
+#[test]
+fn zip_with_generalizes_hadamard() -> Result<(), DimensionError> {
+    let mat_a: Matrix<f64> = matrix! {{2.0, -4.0}, {9.0, 1.5}};
+    let mat_b: Matrix<f64> = matrix! {{4.0, 2.0}, {-3.0, 0.5}};
+    assert_eq!(
+        mat_a.zip_with(&mat_b, |a, b| a * b)?,
+        mat_a.hadamard(&mat_b)?
+    );
+    assert_eq!(
+        mat_a.zip_with(&mat_b, |a, b| a / b)?,
+        mat_a.hadamard_div(&mat_b)?
+    );
+    Ok(())
+}
+#[test]
+fn stack_many() -> Result<(), DimensionError> {
+    let mat_a = matrix! {{1, 2}, {3, 4}};
+    let mat_b = matrix! {{5, 6, 7}, {8, 9, 10}};
+    let wide = mat_a.hstack(&mat_b)?;
+    assert_eq!((wide.rows(), wide.cols()), (2, 5));
+    assert_eq!(wide, matrix! {{1, 2, 5, 6, 7}, {3, 4, 8, 9, 10}});
+    assert_eq!(Matrix::hconcat(&[&mat_a, &mat_b])?, wide);
+    assert_eq!(Matrix::hconcat(&[&mat_a])?, mat_a);
+    assert_eq!(
+        Matrix::vconcat(&[&mat_a, &mat_a, &mat_a])?,
+        mat_a.vstack(&mat_a)?.vstack(&mat_a)?
+    );
+    assert_eq!(
+        mat_a.vstack(&mat_b),
+        Err(DimensionError::NoMatch(
+            mat_a.dims(),
+            mat_b.dims(),
+            "stack vertically".to_owned()
+        ))
+    );
+    assert_eq!(
+        Matrix::vconcat(&[&mat_a, &mat_a, &mat_b]),
+        Err(DimensionError::NoMatch(
+            mat_a.dims(),
+            mat_b.dims(),
+            "stack vertically".to_owned()
+        ))
+    );
+    assert_eq!(
+        Matrix::<i32>::hconcat(&[]),
+        Err(DimensionError::InvalidDimensions)
+    );
+    Ok(())
+}
+#[test]
+fn powi_inverse() -> Result<(), DimensionError> {
+    let mat: Matrix<f64> = matrix! {{1.0, 2.0, 0.0}, {0.0, 1.0, 3.0}, {1.0, 0.0, 1.0}};
+    let inv = mat.clone().inv()?.unwrap();
+    let neg = mat.powi(-5)?.unwrap();
+    let expected = inv.pow(5)?;
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((neg[i][j] - expected[i][j]).abs() < 1e-10);
+        }
+    }
+    let id = (neg * mat.powi(5)?.unwrap())?;
+    for i in 0..3 {
+        for j in 0..3 {
+            let e = if i == j { 1.0 } else { 0.0 };
+            assert!((id[i][j] - e).abs() < 1e-8);
+        }
+    }
+    Ok(())
+}
+#[test]
+fn trace() -> Result<(), DimensionError> {
+    assert_eq!(Matrix::<i32>::one(5)?.trace()?, 5);
+    assert_eq!(Matrix::new(1, 1, -3.5)?.trace()?, -3.5);
+    let mat_a = matrix! {{1, 2, 3}, {4, 5, 6}, {7, 8, 9}};
+    let mat_b = matrix! {{0, 1, 0}, {2, 0, 1}, {1, 1, 1}};
+    // tr(A + B) = tr(A) + tr(B) and tr(AB) = tr(BA)
+    assert_eq!((mat_a.clone() + mat_b.clone())?.trace()?, 16);
+    assert_eq!(
+        (mat_a.clone() * mat_b.clone())?.trace()?,
+        (mat_b * mat_a.clone())?.trace()?
+    );
+    assert_eq!(mat_a.transpose().trace()?, mat_a.trace()?);
+    assert_eq!(Matrix::new(2, 3, 1)?.trace(), Err(DimensionError::NoSquare));
+    Ok(())
+}
+#[test]
+fn diagonal() -> Result<(), DimensionError> {
+    let scale: Matrix<f64> = Matrix::from_diag(&[2.0, -1.0, 0.5])?;
+    let v = vector![1.0, 4.0, 6.0];
+    assert_eq!((scale.clone() * v)?, vector![2.0, -4.0, 3.0]);
+    assert_eq!(scale.diagonal(), vec![2.0, -1.0, 0.5]);
+    assert_eq!(Matrix::from_diag(&[7])?, Matrix::new(1, 1, 7)?);
+    assert_eq!(
+        Matrix::<i32>::from_diag(&[]),
+        Err(DimensionError::InvalidDimensions)
+    );
+
+    assert_eq!(
+        Matrix::from_vec(3, 2, (1..=6).collect())?.diagonal(),
+        vec![1, 4]
+    );
+    // tridiagonal matrix from three diagonals
+    let tri = ((Matrix::from_diag_offset(&[-1, -1], -1)? + Matrix::from_diag(&[2, 2, 2])?)?
+        + Matrix::from_diag_offset(&[-1, -1], 1)?)?;
+    assert_eq!(tri, matrix! {{2, -1, 0}, {-1, 2, -1}, {0, -1, 2}});
+    assert_eq!(
+        Matrix::from_diag_offset(&[1, 2, 3], 0)?,
+        Matrix::from_diag(&[1, 2, 3])?
+    );
+    assert_eq!(
+        Matrix::from_diag_offset(&[5], 2)?,
+        matrix! {{0, 0, 5}, {0, 0, 0}, {0, 0, 0}}
+    );
+    Ok(())
+}
+#[test]
+fn transpose() -> Result<(), DimensionError> {
+    let row = Matrix::from_vec(1, 3, vec![1, 2, 3])?;
+    let col = Matrix::from_vec(3, 1, vec![1, 2, 3])?;
+    assert_eq!(row.transpose(), col);
+    assert_eq!(col.transpose(), row);
+    assert_eq!(row.transpose().transpose(), row);
+    let mut single = Matrix::new(1, 1, 4)?;
+    single.transpose_in_place()?;
+    assert_eq!(single, Matrix::new(1, 1, 4)?);
+    let rect = matrix! {{1, 2, 3}, {4, 5, 6}};
+    assert_eq!(rect.transpose(), matrix! {{1, 4}, {2, 5}, {3, 6}});
+    let mut square = matrix! {{1, 2, 3}, {4, 5, 6}, {7, 8, 9}};
+    square.transpose_in_place()?;
+    assert_eq!(square, matrix! {{1, 4, 7}, {2, 5, 8}, {3, 6, 9}});
+    let mut rect = rect;
+    assert_eq!(rect.transpose_in_place(), Err(DimensionError::NoSquare));
+    Ok(())
+}
+#[test]
+fn rank() -> Result<(), DimensionError> {
+    assert_eq!(Matrix::<i32>::zero(2, 4)?.rank(), 0);
+    assert_eq!(Matrix::<f64>::one(4)?.rank(), 4);
+    assert_eq!(Matrix::from_vec(1, 3, vec![0, 2, 0])?.rank(), 1);
+    assert_eq!(Matrix::from_vec(3, 1, vec![0.0, 0.0, 1e-3])?.rank(), 1);
+    let wide = Matrix::from_vec(
+        3,
+        5,
+        vec![
+            1.0, 2.0, 0.0, 1.0, 3.0, 2.0, 4.0, 1.0, 0.0, 1.0, 3.0, 6.0, 1.0, 1.0, 4.0,
+        ],
+    )?;
+    assert_eq!(wide.rank(), 2);
+    assert_eq!(wide.transpose().rank(), 2);
+    let almost_singular = Matrix::from_vec(2, 2, vec![0.1, 0.3, 0.2, 0.6])?;
+    assert_eq!(almost_singular.rank(), 1);
+    Ok(())
+}
+
+#[test]
+fn from_nested_vecs() -> Result<(), DimensionError> {
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let expected = matrix! {{1, 2, 3}, {4, 5, 6}};
+    assert_eq!(Matrix::from_rows(&rows)?, expected);
+    assert_eq!(Matrix::try_from(rows)?, expected);
+    assert_eq!(
+        Matrix::from_cols(&[vec![1, 4], vec![2, 5], vec![3, 6]])?,
+        expected
+    );
+    assert_eq!(
+        Matrix::from_cols(&[vec![1, 2, 3]])?,
+        matrix! {{1}, {2}, {3}}
+    );
+
+    assert_eq!(
+        Matrix::try_from(vec![vec![1, 2], vec![3, 4], vec![5, 6, 7]]),
+        Err(DimensionError::Ragged(2, 3, 2))
+    );
+    assert_eq!(
+        Matrix::from_cols(&[vec![1, 2], vec![3]]),
+        Err(DimensionError::Ragged(1, 1, 2))
+    );
+    let nested: Vec<Vec<i32>> = expected.clone().into();
+    assert_eq!(nested, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert_eq!(Matrix::try_from(nested)?, expected);
+
+    let empty: Vec<Vec<i32>> = Vec::new();
+    assert_eq!(
+        Matrix::from_rows(&empty),
+        Err(DimensionError::InvalidDimensions)
+    );
+    assert_eq!(
+        Matrix::try_from(vec![Vec::<i32>::new()]),
+        Err(DimensionError::InvalidDimensions)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse() -> Result<(), ParseMatrixError> {
+    let mat: Matrix<f64> = matrix! {{1.5, -2.0, 3.0}, {0.25, 5.0, 6e10}};
+    assert_eq!(mat.to_string().parse::<Matrix<f64>>()?, mat);
+    let ints: Matrix<i64> = " 1  2\t3\n4 5   6\r\n7 8 9\n\n".parse()?;
+    assert_eq!(ints, matrix! {{1, 2, 3}, {4, 5, 6}, {7, 8, 9}});
+
+    assert_eq!(
+        "1 2\n3 4.5".parse::<Matrix<i32>>(),
+        Err(ParseMatrixError::InvalidEntry(1, 1, "4.5".to_owned()))
+    );
+    assert_eq!(
+        "1 2\n3".parse::<Matrix<i32>>(),
+        Err(ParseMatrixError::Dimension(DimensionError::Ragged(2, 1, 2)))
+    );
+    assert_eq!(
+        "1 2\n\n3 4".parse::<Matrix<i32>>(),
+        Err(ParseMatrixError::Dimension(DimensionError::Ragged(2, 0, 2)))
+    );
+    assert_eq!(
+        "".parse::<Matrix<i32>>(),
+        Err(ParseMatrixError::Dimension(
+            DimensionError::InvalidDimensions
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn flat() -> Result<(), DimensionError> {
+    let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let mat = Matrix::from_flat(data.clone(), 3, 2)?;
+    assert_eq!(mat, Matrix::from_vec(3, 2, data.clone())?);
+    assert_eq!(mat[(2, 0)], 5.0);
+    assert_eq!(mat.into_flat(), data);
+    assert_eq!(
+        Matrix::from_flat(data, 4, 2),
+        Err(DimensionError::InvalidInputDimensions(6, 8))
+    );
+    Ok(())
+}
+
+#[test]
+fn display() -> Result<(), DimensionError> {
+    let mat: Matrix<f64> = matrix! {{-1.0 / 3.0, 2.0, 100.0}, {4.5, -12.25, 0.0}};
+    assert_eq!(
+        format!("{:.3}", mat),
+        "-0.333   2.000 100.000\n 4.500 -12.250   0.000"
+    );
+    assert_eq!(
+        format!("{:7.1}", mat),
+        "   -0.3     2.0   100.0\n    4.5   -12.2     0.0"
+    );
+    assert_eq!(
+        mat.to_string(),
+        "-0.3333333333333333      2 100\n                4.5 -12.25   0"
+    );
+    assert_eq!(
+        format!("{:#}", matrix! {{1, 22}, {333, 4}}),
+        "1\t22\n333\t4"
+    );
+    assert_eq!(format!("{:#.1}", matrix! {{1.0, 2.25}}), "1.0\t2.2");
+    assert_eq!(matrix! {{1, -1}, {10, 100}}.to_string(), " 1  -1\n10 100");
+    // views are aligned on their own entries
+    let big = matrix! {{1000, 1, 2}, {3, 4, 5}};
+    assert_eq!(big.view(.., 1..)?.to_string(), "1 2\n4 5");
+    Ok(())
+}
+
+#[test]
+fn latex() {
+    let mat: Matrix<f64> = matrix! {{1.0, -1.0 / 3.0, 2.5}, {0.0, 4.0, -12.0}};
+    assert_eq!(
+        mat.to_latex(LatexEnv::PMatrix, Some(3)),
+        "\\begin{pmatrix}\n1.000 & -0.333 & 2.500 \\\\\n0.000 & 4.000 & -12.000\n\\end{pmatrix}"
+    );
+    assert_eq!(
+        matrix! {{1, 2}, {3, 4}}.to_latex(LatexEnv::VMatrix, None),
+        "\\begin{vmatrix}\n1 & 2 \\\\\n3 & 4\n\\end{vmatrix}"
+    );
+    assert_eq!(
+        matrix! {{1, 2, 3}}.to_latex(LatexEnv::BMatrix, None),
+        "\\begin{bmatrix}\n1 & 2 & 3\n\\end{bmatrix}"
+    );
+    assert_eq!(
+        matrix! {{-7}}.to_latex(LatexEnv::PMatrix, None),
+        "\\begin{pmatrix}\n-7\n\\end{pmatrix}"
+    );
+}
+
+#[test]
+fn markdown() {
+    let mat: Matrix<f64> = matrix! {{1.0, -1.0 / 3.0}, {0.0, 4.0}};
+    assert_eq!(
+        mat.to_markdown(Some(2)),
+        "| | |\n|---:|---:|\n| 1.00 | -0.33 |\n| 0.00 | 4.00 |"
+    );
+    assert_eq!(
+        matrix! {{1, 2, 3}}.to_markdown(None),
+        "| | | |\n|---:|---:|---:|\n| 1 | 2 | 3 |"
+    );
+    assert_eq!(matrix! {{5}}.to_markdown(None), "| |\n|---:|\n| 5 |");
+}
+
+#[test]
+fn simd_mul() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = Matrix::from_fn(13, 21, |i, j| (i as f64 - 2.5 * j as f64).sin())?;
+    let b: Matrix<f64> = Matrix::from_fn(21, 9, |i, j| 1.0 / (i + j + 1) as f64)?;
+    let expected = (a.clone() * b.clone())?;
+    assert!(a.simd_mul(&b)?.approx_eq(&expected, 1e-12));
+
+    let c: Matrix<f32> = Matrix::from_fn(5, 19, |i, j| (i * j) as f32 - 7.0)?;
+    let d: Matrix<f32> = Matrix::from_fn(19, 3, |i, j| (i + 2 * j) as f32 * 0.5)?;
+    assert_eq!(c.simd_mul(&d)?, (c.clone() * d.clone())?);
+
+    assert_eq!(
+        a.simd_mul(&a),
+        Err(DimensionError::NoMatch(
+            a.dims(),
+            a.dims(),
+            "multiply".to_owned()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn checked_ops() -> Result<(), DimensionError> {
+    let a = matrix! {{1, 2, 3}, {4, 5, 6}};
+    let b = matrix! {{6, 5, 4}, {3, 2, 1}};
+    assert_eq!(a.checked_add(&b)?, (a.clone() + b.clone())?);
+    assert_eq!(a.checked_sub(&b)?, (a.clone() - b.clone())?);
+    assert_eq!(a.checked_mul(&b.transpose())?, (a.clone() * b.transpose())?);
+
+    let c: Matrix<i32> = Matrix::one(3)?;
+    assert_eq!(
+        a.checked_add(&c),
+        Err(DimensionError::NoMatch(
+            a.dims(),
+            c.dims(),
+            "add".to_owned()
+        ))
+    );
+    assert_eq!(
+        a.checked_sub(&c),
+        Err(DimensionError::NoMatch(
+            a.dims(),
+            c.dims(),
+            "subtract".to_owned()
+        ))
+    );
+    assert_eq!(
+        (a.clone() - c.clone()),
+        Err(DimensionError::NoMatch(
+            a.dims(),
+            c.dims(),
+            "subtract".to_owned()
+        ))
+    );
+    let err = a.checked_mul(&b).unwrap_err();
+    assert_eq!(
+        err,
+        DimensionError::NoMatch(a.dims(), b.dims(), "multiply".to_owned())
+    );
+    assert!(err
+        .to_string()
+        .contains("Cannot multiply 2x3 matrix with 2x3 matrix."));
+    Ok(())
+}
+
+#[test]
+fn reference_ops() -> Result<(), DimensionError> {
+    let a = matrix! {{1, 2}, {3, 4}};
+    let b = matrix! {{5, 6}, {7, 8}};
+    let c = matrix! {{-1, 0}, {2, 1}};
+
+    assert_eq!((&a + &b)?, (a.clone() + b.clone())?);
+    assert_eq!((a.clone() + &b)?, (a.clone() + b.clone())?);
+    assert_eq!((&a + b.clone())?, (a.clone() + b.clone())?);
+    assert_eq!((&a - &b)?, (a.clone() - b.clone())?);
+    assert_eq!((a.clone() - &b)?, (a.clone() - b.clone())?);
+    assert_eq!((&a - b.clone())?, (a.clone() - b.clone())?);
+    assert_eq!((&a * &b)?, (a.clone() * b.clone())?);
+    assert_eq!((a.clone() * &b)?, (a.clone() * b.clone())?);
+    assert_eq!((&a * b.clone())?, (a.clone() * b.clone())?);
+    assert_eq!(&a * 3, a.clone() * 3);
+    assert_eq!(((&a * &b)? + &c)?, matrix! {{18, 22}, {45, 51}});
+
+    // the owned left operand's buffer is reused
+    let ptr = a.as_ptr();
+    let sum = (a + &b)?;
+    assert_eq!(sum.as_ptr(), ptr);
+
+    let d: Matrix<i32> = Matrix::one(3)?;
+    assert_eq!(
+        &b + &d,
+        Err(DimensionError::NoMatch(
+            b.dims(),
+            d.dims(),
+            "add".to_owned()
+        ))
+    );
+    assert_eq!(
+        b.clone() - &d,
+        Err(DimensionError::NoMatch(
+            b.dims(),
+            d.dims(),
+            "subtract".to_owned()
+        ))
+    );
+    assert_eq!(
+        &b * &d,
+        Err(DimensionError::NoMatch(
+            b.dims(),
+            d.dims(),
+            "multiply".to_owned()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn assign_ops() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{1.0, 2.0, 3.0}, {4.0, 5.0, 6.0}};
+    let delta: Matrix<f64> = matrix! {{0.5, -1.0, 2.0}, {0.0, 1.5, -3.0}};
+
+    let mut acc = a.clone();
+    let ptr = acc.as_ptr();
+    acc += &delta;
+    assert_eq!(acc, (&a + &delta)?);
+    acc -= &delta;
+    assert_eq!(acc, a);
+    acc *= 4.0;
+    assert_eq!(acc, &a * 4.0);
+    acc /= 2.0;
+    assert_eq!(acc, a.clone() * 2.0);
+    acc += delta.clone();
+    acc -= delta.clone();
+    assert_eq!(acc, (a.clone() + a.clone())?);
+    // all operations reused the buffer
+    assert_eq!(acc.as_ptr(), ptr);
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "Dimensions do not match.")]
+fn add_assign_mismatch() {
+    let mut a: Matrix<i32> = Matrix::one(2).unwrap();
+    a += &Matrix::one(3).unwrap();
+}
@@ -0,0 +1,253 @@
+mod common;
+
+use common::assert_mat_eq;
+use libmat::{
+    err::{DimensionError, MatrixError},
+    mat::{Matrix, Vector},
+    matrix, vector,
+};
+use num_traits::Inv;
+
+#[test]
+fn solve_matches_inverse() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{1.0, 5.0, 2.0}, {3.0, 1.0, 4.0}, {2.0, 2.0, 9.0}};
+    let b: Vector<f64> = vector![3.0, -1.0, 2.0];
+    let x = a.solve(&b)?.unwrap();
+    let x_inv = (a.clone().inv()?.unwrap() * b.clone())?;
+    for (x, y) in x.iter().zip(x_inv.iter()) {
+        assert!((x - y).abs() < 1e-12);
+    }
+    let ax = (a * x)?;
+    for (x, y) in ax.iter().zip(b.iter()) {
+        assert!((x - y).abs() < 1e-12);
+    }
+    Ok(())
+}
+
+#[test]
+fn solve_singular() -> Result<(), DimensionError> {
+    let a = matrix! {{1.0, 2.0}, {2.0, 4.0}};
+    assert_eq!(a.solve(&vector![1.0, 2.0])?, None);
+    Ok(())
+}
+
+#[test]
+fn solve_invalid() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = Matrix::one(3)?;
+    let b = vector![1.0, 2.0];
+    assert_eq!(
+        a.solve(&b),
+        Err(DimensionError::NoMatch(
+            a.dims(),
+            Matrix::from(b.clone()).dims(),
+            "solve".to_owned()
+        ))
+    );
+    let c: Matrix<f64> = Matrix::zero(2, 3)?;
+    assert_eq!(c.solve(&b), Err(DimensionError::NoSquare));
+    Ok(())
+}
+
+#[test]
+fn solve_multiple_rhs() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{1.0, 2.0, 7.0}, {3.0, 1.0, 4.0}, {2.0, 8.0, 1.0}};
+    let b = matrix! {{1.0, 0.0}, {2.0, -1.0}, {3.0, 5.0}};
+    let x = a.solve_mat(&b)?.unwrap();
+    assert_eq!(x.dims(), b.dims());
+    for j in 0..2 {
+        let b_j = vector![b[0][j], b[1][j], b[2][j]];
+        let x_j = a.solve(&b_j)?.unwrap();
+        for i in 0..3 {
+            assert!((x[i][j] - x_j[i]).abs() < 1e-12);
+        }
+    }
+
+    let singular = matrix! {{1.0, 2.0}, {2.0, 4.0}};
+    assert_eq!(singular.solve_mat(&Matrix::one(2)?)?, None);
+
+    let c = Matrix::one(2)?;
+    assert_eq!(
+        a.solve_mat(&c),
+        Err(DimensionError::NoMatch(
+            a.dims(),
+            c.dims(),
+            "solve".to_owned()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn solve_column_matrix() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{2.0, 1.0, 1.0}, {4.0, -6.0, 0.0}, {-2.0, 7.0, 2.0}};
+    let b = Matrix::from_vec(3, 1, vec![5.0, -2.0, 9.0])?;
+    let x = a.solve_mat(&b)?.unwrap();
+    assert_eq!(x, Matrix::from_vec(3, 1, vec![1.0, 1.0, 2.0])?);
+    assert_eq!(Vector::from(x), a.solve(&vector![5.0, -2.0, 9.0])?.unwrap());
+    Ok(())
+}
+
+#[test]
+fn least_squares_overdetermined() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{1.0, 1.0}, {1.0, 2.0}, {1.0, 3.0}};
+    let b = matrix! {{1.0}, {2.0}, {2.0}};
+    let x = a.least_squares(&b)?;
+    // normal equations: [[3, 6], [6, 14]] x = [5, 11]
+    assert!((x[0][0] - 2.0 / 3.0).abs() < 1e-12);
+    assert!((x[1][0] - 0.5).abs() < 1e-12);
+
+    let square: Matrix<f64> = matrix! {{2.0, 1.0, 1.0}, {4.0, -6.0, 0.0}, {-2.0, 7.0, 2.0}};
+    let x = square.least_squares(&matrix! {{5.0}, {-2.0}, {9.0}})?;
+    for (i, y) in [1.0, 1.0, 2.0].iter().enumerate() {
+        assert!((x[i][0] - y).abs() < 1e-12);
+    }
+    Ok(())
+}
+
+#[test]
+fn least_squares_minimum_norm() -> Result<(), DimensionError> {
+    // underdetermined: x + y = 2 has minimum norm solution (1, 1)
+    let a: Matrix<f64> = matrix! {{1.0, 1.0}};
+    let x = a.least_squares(&matrix! {{2.0}})?;
+    assert!((x[0][0] - 1.0).abs() < 1e-12);
+    assert!((x[1][0] - 1.0).abs() < 1e-12);
+
+    // rank deficient: both columns equal
+    let a: Matrix<f64> = matrix! {{1.0, 1.0}, {1.0, 1.0}, {1.0, 1.0}};
+    let x = a.least_squares(&matrix! {{1.0}, {2.0}, {3.0}})?;
+    assert!((x[0][0] - 1.0).abs() < 1e-12);
+    assert!((x[1][0] - 1.0).abs() < 1e-12);
+
+    let c = Matrix::one(2)?;
+    assert_eq!(
+        a.least_squares(&c),
+        Err(DimensionError::NoMatch(
+            a.dims(),
+            c.dims(),
+            "solve".to_owned()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn pseudo_inverse() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{2.0, 1.0}, {1.0, 3.0}};
+    let pinv = a.pseudo_inv()?;
+    let inv = a.clone().inv()?.unwrap();
+    for i in 0..2 {
+        for j in 0..2 {
+            assert!((pinv[i][j] - inv[i][j]).abs() < 1e-12);
+        }
+    }
+
+    // rank one: A⁺ = Aᵀ / ‖A‖²_F
+    let b: Matrix<f64> = matrix! {{1.0, 2.0, 2.0}, {2.0, 4.0, 4.0}};
+    let pinv = b.pseudo_inv()?;
+    assert_eq!((pinv.rows(), pinv.cols()), (3, 2));
+    for i in 0..3 {
+        for j in 0..2 {
+            assert!((pinv[i][j] - b[j][i] / 45.0).abs() < 1e-12);
+        }
+    }
+
+    // a large tolerance drops the smaller singular value
+    let c: Matrix<f64> = Matrix::diag_with(2, &[4.0, 1e-3])?;
+    assert_eq!(
+        c.pseudo_inv_with_tol(1e-2)?,
+        Matrix::diag_with(2, &[0.25, 0.0])?
+    );
+
+    assert_eq!(b.pinv()?, b.pseudo_inv()?);
+    assert_eq!(c.pinv_with_tol(1e-2)?, c.pseudo_inv_with_tol(1e-2)?);
+    Ok(())
+}
+
+#[test]
+fn pseudo_inverse_penrose_conditions() -> Result<(), DimensionError> {
+    let matrices: Vec<Matrix<f64>> = vec![
+        matrix! {{1.0, 2.0}, {3.0, 4.0}, {5.0, 6.0}},
+        matrix! {{1.0, 0.0, 2.0, -1.0}, {0.0, 3.0, 1.0, 1.0}},
+        matrix! {{1.0, 2.0, 3.0}, {2.0, 4.0, 6.0}, {1.0, 0.0, 1.0}},
+        Matrix::zero(2, 3)?,
+    ];
+    for a in matrices {
+        let p = a.pseudo_inv()?;
+        assert_eq!((p.rows(), p.cols()), (a.cols(), a.rows()));
+        let ap = (a.clone() * p.clone())?;
+        let pa = (p.clone() * a.clone())?;
+        assert_mat_eq(&(ap.clone() * a.clone())?, &a, 1e-10);
+        assert_mat_eq(&(pa.clone() * p.clone())?, &p, 1e-10);
+        assert_mat_eq(&ap.transpose(), &ap, 1e-10);
+        assert_mat_eq(&pa.transpose(), &pa, 1e-10);
+    }
+    Ok(())
+}
+
+#[test]
+fn conjugate_gradient() -> Result<(), DimensionError> {
+    // tridiagonal SPD matrix of a 1D Poisson problem
+    let n = 20;
+    let a: Matrix<f64> = Matrix::from_fn(n, n, |i, j| match (i as isize - j as isize).abs() {
+        0 => 2.0,
+        1 => -1.0,
+        _ => 0.0,
+    })?;
+    let b: Matrix<f64> = Matrix::from_fn(n, 2, |i, j| if j == 0 { 1.0 } else { i as f64 })?;
+    let x = a.conjugate_gradient(&b, n, 1e-12)?.unwrap();
+    assert_mat_eq(&x, &a.solve_mat(&b)?.unwrap(), 1e-10);
+
+    // a zero right-hand side needs no iterations
+    let zero: Matrix<f64> = Matrix::zero(n, 1)?;
+    assert_eq!(a.conjugate_gradient(&zero, 0, 1e-12)?, Some(zero));
+
+    // too few iterations
+    assert_eq!(a.conjugate_gradient(&b, 3, 1e-12)?, None);
+    // a 1x1 system takes exactly one iteration
+    let one: Matrix<f64> = matrix! {{4.0}};
+    assert_eq!(one.conjugate_gradient(&matrix! {{2.0}}, 0, 1e-12)?, None);
+    assert_eq!(
+        one.conjugate_gradient(&matrix! {{2.0}}, 1, 1e-12)?,
+        Some(matrix! {{0.5}})
+    );
+    // breakdown on an indefinite matrix
+    let indefinite: Matrix<f64> = matrix! {{1.0, 0.0}, {0.0, -1.0}};
+    assert_eq!(
+        indefinite.conjugate_gradient(&matrix! {{0.0}, {1.0}}, 10, 1e-12)?,
+        None
+    );
+
+    let c: Matrix<f64> = Matrix::zero(2, 3)?;
+    assert_eq!(
+        c.conjugate_gradient(&matrix! {{1.0}, {1.0}}, 10, 1e-12),
+        Err(DimensionError::NoSquare)
+    );
+    let d = matrix! {{1.0}, {1.0}};
+    assert_eq!(
+        a.conjugate_gradient(&d, 10, 1e-12),
+        Err(DimensionError::NoMatch(
+            a.dims(),
+            d.dims(),
+            "solve".to_owned()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn try_inverse() -> Result<(), DimensionError> {
+    let a: Matrix<f64> = matrix! {{1.0, 5.0, 2.0}, {3.0, 1.0, 4.0}, {2.0, 2.0, 9.0}};
+    let inv = a.try_inverse().unwrap();
+    // the original matrix is still usable and `Inv` agrees
+    assert_mat_eq(&(a.clone() * inv.clone())?, &Matrix::one(3)?, 1e-10);
+    assert_eq!(a.inv()?, Some(inv));
+
+    let singular: Matrix<f64> = matrix! {{1.0, 2.0}, {2.0, 4.0}};
+    assert_eq!(singular.try_inverse(), Err(MatrixError::Singular));
+    assert_eq!(singular.inv()?, None);
+
+    let rect: Matrix<f64> = Matrix::zero(2, 3)?;
+    assert_eq!(rect.try_inverse(), Err(MatrixError::NotSquare(rect.dims())));
+    assert_eq!(rect.inv(), Err(DimensionError::NoSquare));
+    Ok(())
+}
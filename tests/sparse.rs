@@ -0,0 +1,111 @@
+use libmat::{
+    err::DimensionError,
+    mat::{dims::Dimensions, sparse::SparseMatrix, Matrix, Vector},
+    matrix, vector,
+};
+
+fn sample() -> Matrix<i32> {
+    matrix! {{0, 2, 0, 0}, {1, 0, 0, -3}, {0, 0, 0, 0}, {0, 4, 5, 0}}
+}
+
+#[test]
+fn dense_round_trip() {
+    let mat = sample();
+    let sparse = SparseMatrix::from_dense(&mat);
+    assert_eq!(sparse.nnz(), 5);
+    assert_eq!(sparse.dims(), mat.dims());
+    assert_eq!(sparse.to_dense(), mat);
+    let entries: Vec<(usize, usize, i32)> = sparse.iter().map(|(i, j, x)| (i, j, *x)).collect();
+    assert_eq!(
+        entries,
+        vec![(0, 1, 2), (1, 0, 1), (1, 3, -3), (3, 1, 4), (3, 2, 5)]
+    );
+    let zero: Matrix<i32> = Matrix::zero(2, 3).unwrap();
+    assert_eq!(SparseMatrix::from_dense(&zero).nnz(), 0);
+    assert_eq!(SparseMatrix::from_dense(&zero).to_dense(), zero);
+}
+
+#[test]
+fn triplets() -> Result<(), DimensionError> {
+    // unordered, with duplicates and an entry that cancels out
+    let triplets = [
+        (3, 2, 5),
+        (0, 1, 2),
+        (1, 3, -1),
+        (3, 1, 4),
+        (1, 0, 1),
+        (1, 3, -2),
+        (2, 2, 7),
+        (2, 2, -7),
+    ];
+    let sparse = SparseMatrix::from_triplets(4, 4, &triplets)?;
+    assert_eq!(sparse, SparseMatrix::from_dense(&sample()));
+    assert_eq!(SparseMatrix::<i32>::from_triplets(2, 2, &[])?.nnz(), 0);
+
+    assert_eq!(
+        SparseMatrix::from_triplets(4, 4, &[(4, 0, 1)]),
+        Err(DimensionError::OutOfBounds(4, 4))
+    );
+    assert_eq!(
+        SparseMatrix::from_triplets(4, 3, &[(0, 0, 1), (1, 3, 1)]),
+        Err(DimensionError::OutOfBounds(3, 3))
+    );
+    assert_eq!(
+        SparseMatrix::<i32>::from_triplets(0, 3, &[]),
+        Err(DimensionError::InvalidDimensions)
+    );
+    Ok(())
+}
+
+#[test]
+fn multiply_vector() -> Result<(), DimensionError> {
+    let mat = sample();
+    let sparse = SparseMatrix::from_dense(&mat);
+    let vec = vector![1, -1, 2, 3];
+    assert_eq!((&sparse * &vec)?, (mat * vec.clone())?);
+
+    let short: Vector<i32> = vector![1, 2, 3];
+    assert_eq!(
+        &sparse * &short,
+        Err(DimensionError::NoMatch(
+            sparse.dims(),
+            Dimensions::new(3, 1),
+            "multiply".to_owned()
+        ))
+    );
+    assert!((&sparse * &vec.to_row_vector()).is_err());
+    Ok(())
+}
+
+#[test]
+fn add() -> Result<(), DimensionError> {
+    let a = SparseMatrix::from_dense(&sample());
+    let other = matrix! {{1, -2, 0, 0}, {0, 0, 0, 3}, {0, 6, 0, 0}, {0, 0, 0, 1}};
+    let b = SparseMatrix::from_dense(&other);
+    let sum = (&a + &b)?;
+    assert_eq!(sum.to_dense(), (sample() + other)?);
+    // (0, 1) and (1, 3) cancel out
+    assert_eq!(sum.nnz(), 6);
+    assert_eq!(sum, SparseMatrix::from_dense(&sum.to_dense()));
+
+    let c = SparseMatrix::from_dense(&matrix! {{1, 2}});
+    assert_eq!(
+        &a + &c,
+        Err(DimensionError::NoMatch(
+            a.dims(),
+            c.dims(),
+            "add".to_owned()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn transpose() {
+    let mat = matrix! {{0, 2, 0}, {1, 0, 3}, {0, 0, 0}, {4, 5, 0}};
+    let sparse = SparseMatrix::from_dense(&mat);
+    let transposed = sparse.transpose();
+    assert_eq!(transposed.dims(), mat.transpose().dims());
+    assert_eq!(transposed, SparseMatrix::from_dense(&mat.transpose()));
+    assert_eq!(transposed.transpose(), sparse);
+}
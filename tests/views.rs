@@ -0,0 +1,115 @@
+use libmat::{err::DimensionError, mat::Matrix, matrix};
+
+#[test]
+fn view_matches_submatrix() -> Result<(), DimensionError> {
+    let mat = Matrix::from_vec(4, 5, (0..20).collect())?;
+    for (rows, cols) in [
+        ((0, 4), (0, 5)),
+        ((1, 3), (2, 5)),
+        ((3, 4), (0, 1)),
+        ((0, 2), (4, 5)),
+    ] {
+        let view = mat.view(rows.0..rows.1, cols.0..cols.1)?;
+        let sub = mat.submatrix(rows.0..rows.1, cols.0..cols.1)?;
+        assert_eq!(view.dims(), sub.dims());
+        for i in 0..sub.rows() {
+            assert_eq!(&view[i], &sub[i]);
+            for j in 0..sub.cols() {
+                assert_eq!(view[(i, j)], sub[i][j]);
+                assert_eq!(view.get(i, j), Some(&sub[i][j]));
+            }
+        }
+        assert_eq!(view.get(sub.rows(), 0), None);
+        assert_eq!(view.get(0, sub.cols()), None);
+        assert_eq!(view.to_matrix(), sub);
+        assert_eq!(view.to_string(), sub.to_string());
+    }
+    assert_eq!(
+        mat.view(0..5, ..).map(|v| v.dims()),
+        Err(DimensionError::OutOfBounds(4, 4))
+    );
+    Ok(())
+}
+
+#[test]
+fn view_ops() -> Result<(), DimensionError> {
+    let mat = matrix! {{1, 2, 3}, {4, 5, 6}, {7, 8, 9}};
+    let top = mat.view(..2, ..2)?;
+    let bottom = mat.view(1.., 1..)?;
+    assert_eq!((top + bottom)?, matrix! {{6, 8}, {12, 14}});
+    assert_eq!((bottom - top)?, matrix! {{4, 4}, {4, 4}});
+    assert_eq!(
+        (top * bottom)?,
+        (mat.submatrix(..2, ..2)? * mat.submatrix(1.., 1..)?)?
+    );
+    assert_eq!((top + Matrix::one(2)?)?, matrix! {{2, 2}, {4, 6}});
+    assert_eq!((top * Matrix::one(2)?)?, top.to_matrix());
+
+    let col = mat.view(.., ..1)?;
+    assert_eq!(
+        top + col,
+        Err(DimensionError::NoMatch(
+            top.dims(),
+            col.dims(),
+            "add".to_owned()
+        ))
+    );
+    assert_eq!((col * mat.view(..1, ..)?)?.dims(), mat.dims());
+    Ok(())
+}
+
+#[test]
+fn view_mut() -> Result<(), DimensionError> {
+    let mut mat: Matrix<i32> = Matrix::zero(3, 4)?;
+    {
+        let mut view = mat.view_mut(1..3, 1..3)?;
+        view[0][0] = 1;
+        view[(0, 1)] = 2;
+        *view.get_mut(1, 0).unwrap() = 3;
+        view[1][1] = 4;
+        assert_eq!(view.get_mut(2, 0), None);
+        assert_eq!(view.to_matrix(), matrix! {{1, 2}, {3, 4}});
+    }
+    assert_eq!(
+        mat,
+        Matrix::from_vec(3, 4, vec![0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4, 0])?
+    );
+    mat.view_mut(.., 3..)?.map_mut(|x| *x = -1);
+    assert_eq!(mat.submatrix(.., 3..)?, Matrix::new(3, 1, -1)?);
+    assert_eq!(
+        mat.submatrix(.., ..3)?.iter().filter(|x| **x < 0).count(),
+        0
+    );
+    Ok(())
+}
+
+#[test]
+fn view_read_only_methods() -> Result<(), DimensionError> {
+    let mat: Matrix<f64> =
+        matrix! {{1.0, -2.0, 3.0, 0.5}, {-4.0, 5.0, -6.0, 1.5}, {7.0, -8.0, 9.0, -2.5}};
+    let view = mat.view(..2, 1..)?;
+    let sub = mat.submatrix(..2, 1..)?;
+    assert_eq!(view.frobenius_norm(), sub.frobenius_norm());
+    assert_eq!(view.one_norm(), sub.one_norm());
+    assert_eq!(view.inf_norm(), sub.inf_norm());
+    assert_eq!(view.max_norm(), sub.max_norm());
+    assert_eq!(
+        view.col_iter().collect::<Vec<_>>(),
+        sub.col_iter().collect::<Vec<_>>()
+    );
+    assert!(!view.is_square());
+    assert_eq!(view.trace(), Err(DimensionError::NoSquare));
+
+    let square = mat.view(1.., 1..3)?;
+    assert!(square.is_square());
+    assert_eq!(square.trace()?, 14.0);
+
+    let inner = view.view(1.., ..2)?;
+    assert_eq!(inner.to_matrix(), mat.submatrix(1..2, 1..3)?);
+    assert_eq!(inner[(0, 1)], -6.0);
+    assert_eq!(
+        view.view(..3, ..).err(),
+        Some(DimensionError::OutOfBounds(2, 2))
+    );
+    Ok(())
+}